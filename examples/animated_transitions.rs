@@ -0,0 +1,181 @@
+use iced::{
+    Subscription, Task,
+    time::{Duration, Instant},
+    widget::{button, column, radio, row, text},
+    window,
+};
+use iced_tutorial::{
+    router::{Navigation, Page, Routed, Router},
+    transition::{Animation, Easing, Transition},
+};
+
+fn main() -> iced::Result {
+    iced::application("My App", MyApp::update, MyApp::view)
+        .subscription(MyApp::subscription)
+        .run_with(MyApp::new)
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    TransitionSelected(Transition),
+    Frame(Instant),
+    Page(Routed),
+}
+
+struct MyApp {
+    router: Router,
+    transition: Transition,
+}
+
+impl MyApp {
+    fn new() -> (Self, Task<Message>) {
+        let transition = Transition::Slide;
+        let (router, task) = Router::new(PageA::new());
+        (
+            Self {
+                router: router.animation(Self::animation(transition)),
+                transition,
+            },
+            task.map(Message::Page),
+        )
+    }
+
+    fn animation(transition: Transition) -> Animation {
+        Animation::new(transition)
+            .duration(Duration::from_millis(400))
+            .easing(Easing::EaseInOut)
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::TransitionSelected(transition) => {
+                self.transition = transition;
+                self.router.set_animation(Self::animation(transition));
+                Task::none()
+            }
+            Message::Frame(now) => {
+                self.router.tick(now);
+                Task::none()
+            }
+            Message::Page(message) => match self.router.update(message) {
+                Ok(task) => task.map(Message::Page),
+                Err(stale) => {
+                    eprintln!("{}", stale);
+                    Task::none()
+                }
+            },
+        }
+    }
+
+    fn view(&self) -> iced::Element<'_, Message> {
+        let selected = Some(self.transition);
+        column![
+            row![
+                radio(
+                    "Slide",
+                    Transition::Slide,
+                    selected,
+                    Message::TransitionSelected
+                ),
+                radio(
+                    "Fade",
+                    Transition::Fade,
+                    selected,
+                    Message::TransitionSelected
+                ),
+                radio(
+                    "Push",
+                    Transition::Push,
+                    selected,
+                    Message::TransitionSelected
+                ),
+            ]
+            .spacing(10),
+            self.router.view().map(Message::Page),
+        ]
+        .into()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        if self.router.is_animating() {
+            window::frames().map(Message::Frame)
+        } else {
+            Subscription::none()
+        }
+    }
+}
+
+// Page A
+#[derive(Debug, Clone)]
+enum PageAMessage {
+    ButtonPressed,
+}
+
+struct PageA;
+
+impl PageA {
+    fn new() -> Self {
+        Self
+    }
+}
+
+impl Page for PageA {
+    type Message = PageAMessage;
+
+    fn update(&mut self, message: PageAMessage) -> (Navigation, Task<PageAMessage>) {
+        match message {
+            PageAMessage::ButtonPressed => (Navigation::GoTo(PageB::new(1).into()), Task::none()),
+        }
+    }
+
+    fn view(&self) -> iced::Element<'_, PageAMessage> {
+        column![
+            text("Start"),
+            button("Next").on_press(PageAMessage::ButtonPressed),
+        ]
+        .into()
+    }
+}
+
+// Page B
+#[derive(Debug, Clone)]
+enum PageBMessage {
+    BackButtonPressed,
+    NextButtonPressed,
+    Home,
+}
+
+struct PageB {
+    id: u32,
+}
+
+impl PageB {
+    fn new(id: u32) -> Self {
+        Self { id }
+    }
+}
+
+impl Page for PageB {
+    type Message = PageBMessage;
+
+    fn update(&mut self, message: PageBMessage) -> (Navigation, Task<PageBMessage>) {
+        let navigation = match message {
+            PageBMessage::BackButtonPressed => Navigation::Back,
+            PageBMessage::NextButtonPressed => Navigation::GoTo(PageB::new(self.id + 1).into()),
+            PageBMessage::Home => Navigation::PopToRoot,
+        };
+        (navigation, Task::none())
+    }
+
+    fn view(&self) -> iced::Element<'_, PageBMessage> {
+        column![
+            text(self.id),
+            row![
+                button("Back").on_press(PageBMessage::BackButtonPressed),
+                button("Next").on_press(PageBMessage::NextButtonPressed),
+                button("Home").on_press(PageBMessage::Home),
+            ],
+        ]
+        .into()
+    }
+}
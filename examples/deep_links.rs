@@ -0,0 +1,247 @@
+use iced::{
+    Task,
+    widget::{button, column, row, text},
+};
+use iced_tutorial::router::{AnyPage, Navigation, Page, Route, Routed, Router};
+use std::{fmt, str::FromStr};
+
+fn main() -> iced::Result {
+    iced::application("My App", MyApp::update, MyApp::view).run_with(MyApp::new)
+}
+
+struct MyApp {
+    router: Router,
+}
+
+impl MyApp {
+    fn new() -> (Self, Task<Routed>) {
+        // For example: cargo run --example deep_links -- /items/42/edit
+        let route = match std::env::args().nth(1) {
+            Some(path) => path.parse().unwrap_or_else(|e| {
+                eprintln!("{}", e);
+                AppRoute::Home
+            }),
+            None => AppRoute::Home,
+        };
+
+        let (router, task) = Router::from_route(route);
+        let app = Self { router };
+        app.print_path();
+        (app, task)
+    }
+
+    fn update(&mut self, message: Routed) -> Task<Routed> {
+        let task = self.router.update(message).unwrap_or_else(|stale| {
+            eprintln!("{}", stale);
+            Task::none()
+        });
+        self.print_path();
+        task
+    }
+
+    fn view(&self) -> iced::Element<'_, Routed> {
+        column![
+            text(self.router.path().unwrap_or_default()),
+            self.router.view(),
+        ]
+        .into()
+    }
+
+    fn print_path(&self) {
+        if let Some(path) = self.router.path() {
+            println!("{}", path);
+        }
+    }
+}
+
+// Routes
+#[derive(Debug, Clone, PartialEq)]
+enum AppRoute {
+    Home,
+    Item(u32),
+    EditItem(u32),
+}
+
+impl FromStr for AppRoute {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let segments: Vec<&str> = s
+            .strip_prefix('/')
+            .ok_or_else(|| format!("Route must start with '/': {}", s))?
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .collect();
+
+        let parse_id = |id: &str| {
+            id.parse()
+                .map_err(|_| format!("Invalid item id in route: {}", s))
+        };
+
+        match segments.as_slice() {
+            [] => Ok(AppRoute::Home),
+            ["items", id] => Ok(AppRoute::Item(parse_id(id)?)),
+            ["items", id, "edit"] => Ok(AppRoute::EditItem(parse_id(id)?)),
+            _ => Err(format!("Unknown route: {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for AppRoute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppRoute::Home => write!(f, "/"),
+            AppRoute::Item(id) => write!(f, "/items/{}", id),
+            AppRoute::EditItem(id) => write!(f, "/items/{}/edit", id),
+        }
+    }
+}
+
+impl Route for AppRoute {
+    fn parent(&self) -> Option<Self> {
+        match self {
+            AppRoute::Home => None,
+            AppRoute::Item(_) => Some(AppRoute::Home),
+            AppRoute::EditItem(id) => Some(AppRoute::Item(*id)),
+        }
+    }
+
+    fn page(&self) -> AnyPage {
+        match self {
+            AppRoute::Home => HomePage.into(),
+            AppRoute::Item(id) => ItemPage { id: *id }.into(),
+            AppRoute::EditItem(id) => EditItemPage { id: *id }.into(),
+        }
+    }
+}
+
+// Home page
+#[derive(Debug, Clone)]
+enum HomeMessage {
+    ItemPressed(u32),
+}
+
+struct HomePage;
+
+impl Page for HomePage {
+    type Message = HomeMessage;
+
+    fn update(&mut self, message: HomeMessage) -> (Navigation, Task<HomeMessage>) {
+        match message {
+            HomeMessage::ItemPressed(id) => {
+                (Navigation::GoTo(AppRoute::Item(id).page()), Task::none())
+            }
+        }
+    }
+
+    fn view(&self) -> iced::Element<'_, HomeMessage> {
+        column((1..=3).map(|id| {
+            button(text(format!("Item {}", id)))
+                .on_press(HomeMessage::ItemPressed(id))
+                .into()
+        }))
+        .into()
+    }
+
+    fn path(&self) -> Option<String> {
+        Some(AppRoute::Home.to_string())
+    }
+}
+
+// Item page
+#[derive(Debug, Clone)]
+enum ItemMessage {
+    BackButtonPressed,
+    EditButtonPressed,
+}
+
+struct ItemPage {
+    id: u32,
+}
+
+impl Page for ItemPage {
+    type Message = ItemMessage;
+
+    fn update(&mut self, message: ItemMessage) -> (Navigation, Task<ItemMessage>) {
+        let navigation = match message {
+            ItemMessage::BackButtonPressed => Navigation::Back,
+            ItemMessage::EditButtonPressed => Navigation::GoTo(AppRoute::EditItem(self.id).page()),
+        };
+        (navigation, Task::none())
+    }
+
+    fn view(&self) -> iced::Element<'_, ItemMessage> {
+        column![
+            text(format!("Item {}", self.id)),
+            row![
+                button("Back").on_press(ItemMessage::BackButtonPressed),
+                button("Edit").on_press(ItemMessage::EditButtonPressed),
+            ],
+        ]
+        .into()
+    }
+
+    fn path(&self) -> Option<String> {
+        Some(AppRoute::Item(self.id).to_string())
+    }
+}
+
+// Edit item page
+#[derive(Debug, Clone)]
+enum EditItemMessage {
+    ButtonPressed,
+}
+
+struct EditItemPage {
+    id: u32,
+}
+
+impl Page for EditItemPage {
+    type Message = EditItemMessage;
+
+    fn update(&mut self, message: EditItemMessage) -> (Navigation, Task<EditItemMessage>) {
+        match message {
+            EditItemMessage::ButtonPressed => (Navigation::Back, Task::none()),
+        }
+    }
+
+    fn view(&self) -> iced::Element<'_, EditItemMessage> {
+        column![
+            text(format!("Editing item {}", self.id)),
+            button("Done").on_press(EditItemMessage::ButtonPressed),
+        ]
+        .into()
+    }
+
+    fn path(&self) -> Option<String> {
+        Some(AppRoute::EditItem(self.id).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn routes_survive_a_round_trip() {
+        for path in ["/", "/items/42", "/items/42/edit"] {
+            let route: AppRoute = path.parse().unwrap();
+            assert_eq!(route.to_string(), path);
+            assert_eq!(route.to_string().parse::<AppRoute>(), Ok(route));
+        }
+    }
+
+    #[test]
+    fn bad_paths_are_rejected() {
+        for path in ["items/1", "/items/x", "/nope"] {
+            assert!(path.parse::<AppRoute>().is_err(), "{} was accepted", path);
+        }
+    }
+
+    #[test]
+    fn deep_link_builds_the_whole_stack() {
+        let (router, _) = Router::from_route("/items/42/edit".parse::<AppRoute>().unwrap());
+        assert_eq!(router.depth(), 3);
+        assert_eq!(router.path().as_deref(), Some("/items/42/edit"));
+    }
+}
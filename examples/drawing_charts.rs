@@ -0,0 +1,594 @@
+use iced::{
+    Color, Length, Pixels, Point, Rectangle, Renderer, Size, Theme, Vector,
+    alignment::{Horizontal, Vertical},
+    mouse,
+    widget::{
+        Canvas, button,
+        canvas::{self, Cache, Frame, Geometry, Path, Program, Stroke},
+        column, radio, row,
+    },
+};
+
+fn main() -> iced::Result {
+    iced::application("My App", MyApp::update, MyApp::view)
+        .theme(MyApp::theme)
+        .run()
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    KindSelected(Kind),
+    ToggleTheme,
+    NextData,
+}
+
+struct MyApp {
+    kind: Kind,
+    theme: Theme,
+    data_set: usize,
+    series: Vec<Series>,
+    cache: Cache,
+}
+
+impl Default for MyApp {
+    fn default() -> Self {
+        Self {
+            kind: Kind::Line,
+            theme: Theme::Light,
+            data_set: 0,
+            series: data(0),
+            cache: Cache::new(),
+        }
+    }
+}
+
+impl MyApp {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::KindSelected(kind) => self.kind = kind,
+            Message::ToggleTheme => {
+                self.theme = match self.theme {
+                    Theme::Dark => Theme::Light,
+                    _ => Theme::Dark,
+                };
+            }
+            Message::NextData => {
+                self.data_set += 1;
+                self.series = data(self.data_set);
+            }
+        }
+        // The kind, the colors and the series all change what is in the cache.
+        self.cache.clear();
+    }
+
+    fn view(&self) -> iced::Element<'_, Message> {
+        let kind = Some(self.kind);
+
+        column![
+            row![
+                radio("Line", Kind::Line, kind, Message::KindSelected),
+                radio("Bar", Kind::Bar, kind, Message::KindSelected),
+                radio("Scatter", Kind::Scatter, kind, Message::KindSelected),
+                button("Toggle Theme").on_press(Message::ToggleTheme),
+                button("Next Data").on_press(Message::NextData),
+            ]
+            .spacing(10)
+            .align_y(iced::Alignment::Center),
+            Canvas::new(Chart {
+                kind: self.kind,
+                series: &self.series,
+                cache: &self.cache,
+            })
+            .width(Length::Fill)
+            .height(Length::Fill),
+        ]
+        .spacing(10)
+        .padding(10)
+        .into()
+    }
+
+    fn theme(&self) -> Theme {
+        self.theme.clone()
+    }
+}
+
+struct Series {
+    name: &'static str,
+    values: Vec<Point>,
+}
+
+// Made-up monthly sales, different for each data set.
+fn data(data_set: usize) -> Vec<Series> {
+    ["Apples", "Bananas", "Cherries"]
+        .into_iter()
+        .enumerate()
+        .map(|(s, name)| Series {
+            name,
+            values: (0..12)
+                .map(|month| {
+                    let t = (month + data_set * 5) as f32 * 0.7 + s as f32 * 2.0;
+                    Point::new(month as f32, 20.0 + 15.0 * t.sin() + 5.0 * s as f32)
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Line,
+    Bar,
+    Scatter,
+}
+
+const TICK_COUNT: usize = 6;
+const MARGIN: f32 = 10.0;
+const AXIS_MARGIN: f32 = 40.0;
+const TEXT_SIZE: f32 = 14.0;
+const HOVER_DISTANCE: f32 = 30.0;
+
+// Returns evenly spaced ticks that cover `min..=max`, with a step of 1, 2 or 5 times a power of
+// ten, and the step.
+fn nice_ticks(min: f32, max: f32, count: usize) -> (Vec<f32>, f32) {
+    let (min, max) = if max - min > f32::EPSILON {
+        (min, max)
+    } else {
+        (min - 1.0, max + 1.0)
+    };
+
+    let range = nice_number(max - min, false);
+    let step = nice_number(range / (count - 1) as f32, true);
+    let first = (min / step).floor() * step;
+    let last = (max / step).ceil() * step;
+    let steps = ((last - first) / step).round() as usize;
+
+    ((0..=steps).map(|i| first + i as f32 * step).collect(), step)
+}
+
+// Picks a number close to `value` among 1, 2, 5 and 10 times a power of ten. With `round`, the
+// closest one is picked, and otherwise the smallest one that is not less than `value`.
+fn nice_number(value: f32, round: bool) -> f32 {
+    let power = 10_f32.powf(value.log10().floor());
+    let fraction = value / power;
+
+    let nice = if round {
+        match fraction {
+            f if f < 1.5 => 1.0,
+            f if f < 3.0 => 2.0,
+            f if f < 7.0 => 5.0,
+            _ => 10.0,
+        }
+    } else {
+        match fraction {
+            f if f <= 1.0 => 1.0,
+            f if f <= 2.0 => 2.0,
+            f if f <= 5.0 => 5.0,
+            _ => 10.0,
+        }
+    };
+
+    nice * power
+}
+
+struct Axis {
+    min: f32,
+    max: f32,
+    ticks: Vec<f32>,
+    step: f32,
+}
+
+impl Axis {
+    // The axis spans whole ticks, so the first and the last ticks are at its ends.
+    fn nice(min: f32, max: f32) -> Self {
+        let (ticks, step) = nice_ticks(min, max, TICK_COUNT);
+
+        Self {
+            min: ticks[0],
+            max: ticks[ticks.len() - 1],
+            ticks,
+            step,
+        }
+    }
+
+    // One tick for each category, with half a category of room on both sides for the bars.
+    fn categories(count: usize) -> Self {
+        Self {
+            min: -0.5,
+            max: count as f32 - 0.5,
+            ticks: (0..count).map(|i| i as f32).collect(),
+            step: 1.0,
+        }
+    }
+
+    fn fraction(&self, value: f32) -> f32 {
+        (value - self.min) / (self.max - self.min)
+    }
+
+    // Shows as many decimals as the step needs.
+    fn label(&self, value: f32) -> String {
+        let decimals = (-self.step.log10().floor()).max(0.0) as usize;
+        format!("{:.*}", decimals, value)
+    }
+}
+
+// Where the data goes on the canvas.
+struct Plot {
+    area: Rectangle,
+    x: Axis,
+    y: Axis,
+}
+
+impl Plot {
+    fn new(kind: Kind, series: &[Series], size: Size) -> Self {
+        let values = || series.iter().flat_map(|series| &series.values);
+        let (x_min, x_max) = bounds(values().map(|p| p.x));
+        let (y_min, y_max) = bounds(values().map(|p| p.y));
+
+        let (x, y) = match kind {
+            // Bars grow from zero, so zero is always on the y axis.
+            Kind::Bar => (
+                Axis::categories((x_max + 1.0).max(0.0) as usize),
+                Axis::nice(y_min.min(0.0), y_max.max(0.0)),
+            ),
+            Kind::Line | Kind::Scatter => (Axis::nice(x_min, x_max), Axis::nice(y_min, y_max)),
+        };
+
+        Self {
+            area: Rectangle::new(
+                Point::new(AXIS_MARGIN, MARGIN),
+                Size::new(
+                    (size.width - AXIS_MARGIN - MARGIN).max(0.0),
+                    (size.height - AXIS_MARGIN - MARGIN).max(0.0),
+                ),
+            ),
+            x,
+            y,
+        }
+    }
+
+    fn to_screen(&self, value: Point) -> Point {
+        Point::new(
+            self.area.x + self.x.fraction(value.x) * self.area.width,
+            self.area.y + (1.0 - self.y.fraction(value.y)) * self.area.height,
+        )
+    }
+
+    // The bars of a category stand side by side, one for each series.
+    fn bar(&self, series: usize, series_count: usize, value: Point) -> Rectangle {
+        let group = 0.8 * self.area.width / (self.x.max - self.x.min);
+        let width = group / series_count as f32;
+        let top = self.to_screen(value);
+        let bottom = self.to_screen(Point::new(value.x, 0.0));
+
+        Rectangle::new(
+            Point::new(
+                top.x - group / 2.0 + series as f32 * width,
+                top.y.min(bottom.y),
+            ),
+            Size::new(width, (bottom.y - top.y).abs()),
+        )
+    }
+}
+
+// Returns the smallest and the largest value, or `(0.0, 1.0)` if there are none.
+fn bounds(values: impl Iterator<Item = f32>) -> (f32, f32) {
+    values
+        .fold(None, |bounds, value| match bounds {
+            None => Some((value, value)),
+            Some((min, max)) => Some((value.min(min), value.max(max))),
+        })
+        .unwrap_or((0.0, 1.0))
+}
+
+fn color(theme: &Theme, series: usize) -> Color {
+    let palette = theme.extended_palette();
+
+    [
+        palette.primary.base.color,
+        palette.success.base.color,
+        palette.danger.base.color,
+        palette.secondary.base.color,
+    ][series % 4]
+}
+
+fn label(content: String, position: Point, color: Color) -> canvas::Text {
+    canvas::Text {
+        content,
+        position,
+        color,
+        size: Pixels(TEXT_SIZE),
+        ..canvas::Text::default()
+    }
+}
+
+struct Chart<'a> {
+    kind: Kind,
+    series: &'a [Series],
+    cache: &'a Cache,
+}
+
+impl Chart<'_> {
+    // Where a value is marked: the point itself, or the top of its bar.
+    fn anchor(&self, plot: &Plot, series: usize, value: Point) -> Point {
+        match self.kind {
+            Kind::Bar => {
+                let bar = plot.bar(series, self.series.len(), value);
+                Point::new(bar.center_x(), plot.to_screen(value).y)
+            }
+            Kind::Line | Kind::Scatter => plot.to_screen(value),
+        }
+    }
+
+    // Returns the series and the value nearest to the cursor, if it is close enough.
+    fn nearest(&self, plot: &Plot, cursor: Point) -> Option<(usize, Point)> {
+        self.series
+            .iter()
+            .enumerate()
+            .flat_map(|(s, series)| series.values.iter().map(move |value| (s, *value)))
+            .map(|(s, value)| {
+                let distance = match self.kind {
+                    Kind::Bar if plot.bar(s, self.series.len(), value).contains(cursor) => 0.0,
+                    _ => self.anchor(plot, s, value).distance(cursor),
+                };
+                (distance, s, value)
+            })
+            .filter(|(distance, _, _)| *distance <= HOVER_DISTANCE)
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, s, value)| (s, value))
+    }
+
+    fn draw_axes(&self, frame: &mut Frame, plot: &Plot, theme: &Theme) {
+        let palette = theme.extended_palette();
+        let area = plot.area;
+        let grid = Stroke::default().with_color(palette.background.strong.color);
+        let text = palette.background.base.text;
+
+        for &tick in &plot.y.ticks {
+            let y = plot.to_screen(Point::new(plot.x.min, tick)).y;
+            frame.stroke(
+                &Path::line(Point::new(area.x, y), Point::new(area.x + area.width, y)),
+                grid,
+            );
+            frame.fill_text(canvas::Text {
+                horizontal_alignment: Horizontal::Right,
+                vertical_alignment: Vertical::Center,
+                ..label(plot.y.label(tick), Point::new(area.x - 5.0, y), text)
+            });
+        }
+
+        for &tick in &plot.x.ticks {
+            let x = plot.to_screen(Point::new(tick, plot.y.min)).x;
+            let bottom = area.y + area.height;
+            frame.stroke(
+                &Path::line(Point::new(x, bottom), Point::new(x, bottom + 5.0)),
+                grid,
+            );
+            frame.fill_text(canvas::Text {
+                horizontal_alignment: Horizontal::Center,
+                ..label(plot.x.label(tick), Point::new(x, bottom + 8.0), text)
+            });
+        }
+
+        let axes = Path::new(|builder| {
+            builder.move_to(Point::new(area.x, area.y));
+            builder.line_to(Point::new(area.x, area.y + area.height));
+            builder.line_to(Point::new(area.x + area.width, area.y + area.height));
+        });
+        frame.stroke(&axes, Stroke::default().with_color(text));
+    }
+
+    fn draw_series(&self, frame: &mut Frame, plot: &Plot, theme: &Theme) {
+        for (s, series) in self.series.iter().enumerate() {
+            let color = color(theme, s);
+
+            match self.kind {
+                Kind::Line => {
+                    let line = Path::new(|builder| {
+                        for (i, value) in series.values.iter().enumerate() {
+                            if i == 0 {
+                                builder.move_to(plot.to_screen(*value));
+                            } else {
+                                builder.line_to(plot.to_screen(*value));
+                            }
+                        }
+                    });
+                    frame.stroke(&line, Stroke::default().with_width(2.0).with_color(color));
+                }
+                Kind::Bar => {
+                    for value in &series.values {
+                        let bar = plot.bar(s, self.series.len(), *value);
+                        frame.fill_rectangle(bar.position(), bar.size(), color);
+                    }
+                }
+                Kind::Scatter => {
+                    for value in &series.values {
+                        frame.fill(&Path::circle(plot.to_screen(*value), 4.0), color);
+                    }
+                }
+            }
+        }
+    }
+
+    // The legend sits in the top right corner of the plot.
+    fn draw_legend(&self, frame: &mut Frame, plot: &Plot, theme: &Theme) {
+        let palette = theme.extended_palette();
+        let row_height = TEXT_SIZE + 6.0;
+        let size = Size::new(100.0, self.series.len() as f32 * row_height + MARGIN);
+        let position = Point::new(
+            plot.area.x + plot.area.width - size.width - MARGIN,
+            plot.area.y + MARGIN,
+        );
+
+        frame.fill_rectangle(position, size, palette.background.base.color);
+        frame.stroke(
+            &Path::rectangle(position, size),
+            Stroke::default().with_color(palette.background.strong.color),
+        );
+
+        for (s, series) in self.series.iter().enumerate() {
+            let row = position + Vector::new(MARGIN, MARGIN / 2.0 + s as f32 * row_height);
+            frame.fill_rectangle(
+                row + Vector::new(0.0, 3.0),
+                Size::new(10.0, 10.0),
+                color(theme, s),
+            );
+            frame.fill_text(label(
+                series.name.to_string(),
+                row + Vector::new(16.0, 0.0),
+                palette.background.base.text,
+            ));
+        }
+    }
+
+    fn draw_tooltip(
+        &self,
+        frame: &mut Frame,
+        plot: &Plot,
+        theme: &Theme,
+        (s, value): (usize, Point),
+    ) {
+        let palette = theme.extended_palette();
+        let anchor = self.anchor(plot, s, value);
+
+        frame.stroke(
+            &Path::circle(anchor, 6.0),
+            Stroke::default()
+                .with_width(2.0)
+                .with_color(palette.background.base.text),
+        );
+
+        let content = format!(
+            "{}: {} at {}",
+            self.series[s].name,
+            plot.y.label(value.y),
+            plot.x.label(value.x)
+        );
+        // The canvas cannot measure text, so the width is estimated from the number of characters.
+        let size = Size::new(
+            content.len() as f32 * TEXT_SIZE * 0.6 + 2.0 * MARGIN,
+            TEXT_SIZE + MARGIN,
+        );
+        // The tooltip is kept inside the canvas.
+        let position = Point::new(
+            (anchor.x + MARGIN).min(frame.width() - size.width).max(0.0),
+            (anchor.y - size.height - MARGIN).max(0.0),
+        );
+
+        frame.fill_rectangle(position, size, palette.background.strong.color);
+        frame.fill_text(label(
+            content,
+            position + Vector::new(MARGIN, MARGIN / 2.0),
+            palette.background.strong.text,
+        ));
+    }
+}
+
+impl<Message> Program<Message> for Chart<'_> {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let plot = Plot::new(self.kind, self.series, bounds.size());
+
+        // The chart is only tessellated again when the app clears the cache, or on a resize.
+        let chart = self.cache.draw(renderer, bounds.size(), |frame| {
+            self.draw_axes(frame, &plot, theme);
+            self.draw_series(frame, &plot, theme);
+            self.draw_legend(frame, &plot, theme);
+        });
+
+        let mut hover = Frame::new(renderer, bounds.size());
+
+        if let Some(nearest) = cursor
+            .position_in(bounds)
+            .and_then(|position| self.nearest(&plot, position))
+        {
+            self.draw_tooltip(&mut hover, &plot, theme, nearest);
+        }
+
+        vec![chart, hover.into_geometry()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_ticks(min: f32, max: f32, ticks: &[f32], step: f32) {
+        let (actual, actual_step) = nice_ticks(min, max, TICK_COUNT);
+        assert!((actual_step - step).abs() < 1e-6, "step {actual_step}");
+        assert_eq!(actual.len(), ticks.len(), "ticks {actual:?}");
+        for (actual, tick) in actual.iter().zip(ticks) {
+            assert!((actual - tick).abs() < 1e-4, "ticks {actual:?}");
+        }
+    }
+
+    fn labels(axis: &Axis) -> Vec<String> {
+        axis.ticks.iter().map(|&tick| axis.label(tick)).collect()
+    }
+
+    #[test]
+    fn ticks_cover_typical_ranges() {
+        assert_ticks(0.0, 100.0, &[0.0, 20.0, 40.0, 60.0, 80.0, 100.0], 20.0);
+        assert_ticks(3.0, 97.0, &[0.0, 20.0, 40.0, 60.0, 80.0, 100.0], 20.0);
+        assert_ticks(0.0, 0.17, &[0.0, 0.05, 0.1, 0.15, 0.2], 0.05);
+        assert_ticks(
+            1200.0,
+            4800.0,
+            &[1000.0, 2000.0, 3000.0, 4000.0, 5000.0],
+            1000.0,
+        );
+    }
+
+    #[test]
+    fn a_zero_width_range_is_widened_around_its_value() {
+        assert_ticks(5.0, 5.0, &[4.0, 4.5, 5.0, 5.5, 6.0], 0.5);
+        assert_ticks(0.0, 0.0, &[-1.0, -0.5, 0.0, 0.5, 1.0], 0.5);
+    }
+
+    #[test]
+    fn ticks_cover_negative_ranges() {
+        assert_ticks(-73.0, -12.0, &[-80.0, -60.0, -40.0, -20.0, 0.0], 20.0);
+        assert_ticks(
+            -50.0,
+            50.0,
+            &[-60.0, -40.0, -20.0, 0.0, 20.0, 40.0, 60.0],
+            20.0,
+        );
+    }
+
+    #[test]
+    fn labels_show_as_many_decimals_as_the_step_needs() {
+        assert_eq!(
+            labels(&Axis::nice(0.0, 100.0)),
+            ["0", "20", "40", "60", "80", "100"]
+        );
+        assert_eq!(
+            labels(&Axis::nice(5.0, 5.0)),
+            ["4.0", "4.5", "5.0", "5.5", "6.0"]
+        );
+        assert_eq!(
+            labels(&Axis::nice(0.0, 0.17)),
+            ["0.00", "0.05", "0.10", "0.15", "0.20"]
+        );
+        assert_eq!(labels(&Axis::categories(3)), ["0", "1", "2"]);
+    }
+
+    #[test]
+    fn labels_around_zero_have_no_negative_zero() {
+        assert_eq!(
+            labels(&Axis::nice(-0.23, 0.21)),
+            ["-0.3", "-0.2", "-0.1", "0.0", "0.1", "0.2", "0.3"]
+        );
+        assert_eq!(
+            labels(&Axis::nice(-73.0, -12.0)),
+            ["-80", "-60", "-40", "-20", "0"]
+        );
+    }
+}
@@ -0,0 +1,859 @@
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use iced::{
+    Length, Point, Radians, Rectangle, Renderer, Size, Theme, Vector,
+    keyboard::{self, Key, Modifiers, key::Named},
+    mouse,
+    widget::{
+        Canvas,
+        canvas::{Cache, Event, Frame, Geometry, Path, Program, Stroke, event, path::arc},
+        column, radio, row, text,
+    },
+};
+
+fn main() -> iced::Result {
+    iced::run("My App", MyApp::update, MyApp::view)
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    ToolSelected(Tool),
+    ShapeAdded(Shape),
+    ShapesMoved(Vec<usize>, Vector),
+    ShapeChanged(usize, Shape),
+    ShapesRemoved(Vec<usize>),
+}
+
+struct MyApp {
+    tool: Tool,
+    shapes: Vec<Shape>,
+    cache: Cache,
+}
+
+impl Default for MyApp {
+    fn default() -> Self {
+        Self {
+            tool: Tool::Select,
+            shapes: vec![
+                Shape::between(
+                    Kind::Rectangle,
+                    Point::new(50.0, 50.0),
+                    Point::new(200.0, 150.0),
+                ),
+                Shape::between(
+                    Kind::Ellipse,
+                    Point::new(250.0, 80.0),
+                    Point::new(400.0, 200.0),
+                ),
+                Shape::polyline(&[
+                    Point::new(100.0, 250.0),
+                    Point::new(180.0, 320.0),
+                    Point::new(260.0, 240.0),
+                    Point::new(340.0, 330.0),
+                ]),
+            ],
+            cache: Cache::new(),
+        }
+    }
+}
+
+impl MyApp {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::ToolSelected(tool) => self.tool = tool,
+            Message::ShapeAdded(shape) => self.shapes.push(shape),
+            Message::ShapesMoved(indices, delta) => {
+                for i in indices {
+                    if let Some(shape) = self.shapes.get_mut(i) {
+                        shape.center = shape.center + delta;
+                    }
+                }
+            }
+            Message::ShapeChanged(i, shape) => {
+                if let Some(old) = self.shapes.get_mut(i) {
+                    *old = shape;
+                }
+            }
+            Message::ShapesRemoved(mut indices) => {
+                // Removing from the back keeps the remaining indices valid.
+                indices.sort_unstable();
+                indices.dedup();
+                for i in indices.into_iter().rev() {
+                    if i < self.shapes.len() {
+                        self.shapes.remove(i);
+                    }
+                }
+            }
+        }
+        self.cache.clear();
+    }
+
+    fn view(&self) -> iced::Element<'_, Message> {
+        let tool = Some(self.tool);
+        let help = match self.tool {
+            Tool::Select => {
+                "Click or drag around shapes to select them, with Shift to add to the selection. Press Delete to remove them."
+            }
+            Tool::Rectangle | Tool::Ellipse => "Drag to draw a shape.",
+            Tool::Polyline => "Click to add points, Enter to finish, and Escape to cancel.",
+        };
+
+        column![
+            row![
+                radio("Select", Tool::Select, tool, Message::ToolSelected),
+                radio("Rectangle", Tool::Rectangle, tool, Message::ToolSelected),
+                radio("Ellipse", Tool::Ellipse, tool, Message::ToolSelected),
+                radio("Polyline", Tool::Polyline, tool, Message::ToolSelected),
+            ]
+            .spacing(10),
+            text(help),
+            Canvas::new(Editor {
+                shapes: &self.shapes,
+                tool: self.tool,
+                cache: &self.cache,
+            })
+            .width(Length::Fill)
+            .height(Length::Fill),
+        ]
+        .spacing(10)
+        .padding(10)
+        .into()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tool {
+    Select,
+    Rectangle,
+    Ellipse,
+    Polyline,
+}
+
+const MIN_SIZE: f32 = 4.0;
+const TOLERANCE: f32 = 4.0;
+const HANDLE_SIZE: f32 = 8.0;
+const ROTATE_HANDLE_DISTANCE: f32 = 25.0;
+
+#[derive(Debug, Clone)]
+enum Kind {
+    Rectangle,
+    Ellipse,
+    // The points are relative to the center, in units of the size, so they follow resizing.
+    Polyline(Vec<Vector>),
+}
+
+// Every shape is a box of `size` around `center`, rotated clockwise by `rotation` radians.
+#[derive(Debug, Clone)]
+struct Shape {
+    kind: Kind,
+    center: Point,
+    size: Size,
+    rotation: f32,
+}
+
+impl Shape {
+    fn between(kind: Kind, a: Point, b: Point) -> Self {
+        Self {
+            kind,
+            center: Point::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0),
+            size: Size::new((b.x - a.x).abs(), (b.y - a.y).abs()),
+            rotation: 0.0,
+        }
+    }
+
+    fn polyline(points: &[Point]) -> Self {
+        let bounds = rectangle_around(points);
+        let center = bounds.center();
+        let size = Size::new(bounds.width.max(1.0), bounds.height.max(1.0));
+        let units = points
+            .iter()
+            .map(|p| {
+                Vector::new(
+                    (p.x - center.x) / size.width,
+                    (p.y - center.y) / size.height,
+                )
+            })
+            .collect();
+
+        Self {
+            kind: Kind::Polyline(units),
+            center,
+            size,
+            rotation: 0.0,
+        }
+    }
+
+    // Converts a point of the canvas into the frame of the shape, where the center is the origin
+    // and the shape is not rotated.
+    fn to_local(&self, point: Point) -> Vector {
+        rotate(point - self.center, -self.rotation)
+    }
+
+    fn to_canvas(&self, local: Vector) -> Point {
+        self.center + rotate(local, self.rotation)
+    }
+
+    fn vertices<'a>(&self, units: &'a [Vector]) -> impl Iterator<Item = Vector> + 'a {
+        let size = self.size;
+        units
+            .iter()
+            .map(move |unit| Vector::new(unit.x * size.width, unit.y * size.height))
+    }
+
+    // The corners in clockwise order, starting from the top left.
+    fn corners(&self) -> [Point; 4] {
+        let (w, h) = (self.size.width / 2.0, self.size.height / 2.0);
+        [
+            Vector::new(-w, -h),
+            Vector::new(w, -h),
+            Vector::new(w, h),
+            Vector::new(-w, h),
+        ]
+        .map(|corner| self.to_canvas(corner))
+    }
+
+    fn rotate_handle(&self) -> Point {
+        self.to_canvas(Vector::new(
+            0.0,
+            -self.size.height / 2.0 - ROTATE_HANDLE_DISTANCE,
+        ))
+    }
+
+    fn bounds(&self) -> Rectangle {
+        rectangle_around(&self.corners())
+    }
+
+    // Whether the point is inside the shape, or on the line for polylines, give or take `tolerance`.
+    fn contains(&self, point: Point, tolerance: f32) -> bool {
+        let local = self.to_local(point);
+        let (a, b) = (
+            self.size.width / 2.0 + tolerance,
+            self.size.height / 2.0 + tolerance,
+        );
+
+        match &self.kind {
+            Kind::Rectangle => local.x.abs() <= a && local.y.abs() <= b,
+            Kind::Ellipse => (local.x / a).powi(2) + (local.y / b).powi(2) <= 1.0,
+            Kind::Polyline(units) => {
+                let vertices: Vec<_> = self.vertices(units).collect();
+                vertices
+                    .windows(2)
+                    .any(|segment| distance_to_segment(local, segment[0], segment[1]) <= tolerance)
+            }
+        }
+    }
+
+    // Resizes the shape so that `fixed` stays where it is and the opposite corner is at `cursor`.
+    fn resized(&self, fixed: Point, cursor: Point) -> Self {
+        let diagonal = rotate(cursor - fixed, -self.rotation);
+        let size = Size::new(
+            diagonal.x.abs().max(MIN_SIZE),
+            diagonal.y.abs().max(MIN_SIZE),
+        );
+        let half = Vector::new(
+            size.width / 2.0 * diagonal.x.signum(),
+            size.height / 2.0 * diagonal.y.signum(),
+        );
+
+        Self {
+            center: fixed + rotate(half, self.rotation),
+            size,
+            ..self.clone()
+        }
+    }
+
+    // Rotates the shape so that its rotate handle points at `cursor`.
+    fn rotated_towards(&self, cursor: Point) -> Self {
+        let direction = cursor - self.center;
+
+        Self {
+            rotation: direction.y.atan2(direction.x) + FRAC_PI_2,
+            ..self.clone()
+        }
+    }
+
+    // Returns the path in the frame of the shape.
+    fn path(&self) -> Path {
+        let (w, h) = (self.size.width, self.size.height);
+
+        match &self.kind {
+            Kind::Rectangle => Path::rectangle(Point::new(-w / 2.0, -h / 2.0), self.size),
+            Kind::Ellipse => Path::new(|builder| {
+                builder.ellipse(arc::Elliptical {
+                    center: Point::ORIGIN,
+                    radii: Vector::new(w / 2.0, h / 2.0),
+                    rotation: Radians(0.0),
+                    start_angle: Radians(0.0),
+                    end_angle: Radians(TAU),
+                });
+            }),
+            Kind::Polyline(units) => Path::new(|builder| {
+                for (i, vertex) in self.vertices(units).enumerate() {
+                    if i == 0 {
+                        builder.move_to(Point::ORIGIN + vertex);
+                    } else {
+                        builder.line_to(Point::ORIGIN + vertex);
+                    }
+                }
+            }),
+        }
+    }
+
+    fn fill_and_stroke(&self, frame: &mut Frame, fill: Option<iced::Color>, stroke: Stroke<'_>) {
+        frame.with_save(|frame| {
+            frame.translate(self.center - Point::ORIGIN);
+            frame.rotate(self.rotation);
+
+            let path = self.path();
+            if let (Some(fill), false) = (fill, matches!(self.kind, Kind::Polyline(_))) {
+                frame.fill(&path, fill);
+            }
+            frame.stroke(&path, stroke);
+        });
+    }
+}
+
+// Rotates a vector clockwise on the screen, since the y axis points down.
+fn rotate(vector: Vector, angle: f32) -> Vector {
+    let (sin, cos) = angle.sin_cos();
+    Vector::new(
+        vector.x * cos - vector.y * sin,
+        vector.x * sin + vector.y * cos,
+    )
+}
+
+fn distance_to_segment(point: Vector, a: Vector, b: Vector) -> f32 {
+    let ab = b - a;
+    let length_squared = ab.x * ab.x + ab.y * ab.y;
+    let t = if length_squared == 0.0 {
+        0.0
+    } else {
+        (((point.x - a.x) * ab.x + (point.y - a.y) * ab.y) / length_squared).clamp(0.0, 1.0)
+    };
+    let offset = point - (a + ab * t);
+    (offset.x * offset.x + offset.y * offset.y).sqrt()
+}
+
+fn rectangle_around(points: &[Point]) -> Rectangle {
+    let (min, max) = points.iter().fold(
+        (
+            Point::new(f32::INFINITY, f32::INFINITY),
+            Point::new(f32::NEG_INFINITY, f32::NEG_INFINITY),
+        ),
+        |(min, max), p| {
+            (
+                Point::new(min.x.min(p.x), min.y.min(p.y)),
+                Point::new(max.x.max(p.x), max.y.max(p.y)),
+            )
+        },
+    );
+    Rectangle::new(min, Size::new(max.x - min.x, max.y - min.y))
+}
+
+struct Editor<'a> {
+    shapes: &'a [Shape],
+    tool: Tool,
+    cache: &'a Cache,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Handle {
+    Corner(usize),
+    Rotate,
+}
+
+impl Editor<'_> {
+    // Returns the topmost shape under the point, which is the last one drawn.
+    fn shape_at(&self, point: Point) -> Option<usize> {
+        self.shapes
+            .iter()
+            .rposition(|shape| shape.contains(point, TOLERANCE))
+    }
+
+    // Handles are shown only when a single shape is selected.
+    fn handle_at(&self, selection: &[usize], point: Point) -> Option<Handle> {
+        let [i] = selection else {
+            return None;
+        };
+        let shape = self.shapes.get(*i)?;
+
+        if shape.rotate_handle().distance(point) <= HANDLE_SIZE {
+            return Some(Handle::Rotate);
+        }
+        shape
+            .corners()
+            .iter()
+            .position(|corner| corner.distance(point) <= HANDLE_SIZE)
+            .map(Handle::Corner)
+    }
+}
+
+#[derive(Debug, Default)]
+enum Action {
+    #[default]
+    Idle,
+    Moving {
+        last: Point,
+    },
+    Resizing {
+        index: usize,
+        fixed: Point,
+    },
+    Rotating {
+        index: usize,
+    },
+    Selecting {
+        start: Point,
+    },
+    Drawing {
+        start: Point,
+    },
+    DrawingPolyline {
+        points: Vec<Point>,
+    },
+}
+
+#[derive(Default)]
+struct State {
+    selection: Vec<usize>,
+    action: Action,
+    modifiers: Modifiers,
+}
+
+impl Program<Message> for Editor<'_> {
+    type State = State;
+
+    fn update(
+        &self,
+        state: &mut Self::State,
+        event: Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> (event::Status, Option<Message>) {
+        // The document may have changed since the last event.
+        state.selection.retain(|&i| i < self.shapes.len());
+        if self.tool != Tool::Polyline && matches!(state.action, Action::DrawingPolyline { .. }) {
+            state.action = Action::Idle;
+        }
+
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+                (event::Status::Ignored, None)
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    return (event::Status::Ignored, None);
+                };
+                self.press(state, position);
+                (event::Status::Captured, None)
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let Some(position) = cursor.position_from(bounds.position()) else {
+                    return (event::Status::Ignored, None);
+                };
+                self.drag(state, position)
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_from(bounds.position()) else {
+                    return (event::Status::Ignored, None);
+                };
+                self.release(state, position)
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: Key::Named(Named::Enter),
+                ..
+            }) => match std::mem::take(&mut state.action) {
+                Action::DrawingPolyline { points } if points.len() >= 2 => {
+                    state.selection = vec![self.shapes.len()];
+                    (
+                        event::Status::Captured,
+                        Some(Message::ShapeAdded(Shape::polyline(&points))),
+                    )
+                }
+                action => {
+                    state.action = action;
+                    (event::Status::Ignored, None)
+                }
+            },
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: Key::Named(Named::Escape),
+                ..
+            }) => match std::mem::take(&mut state.action) {
+                Action::Idle => (event::Status::Ignored, None),
+                _ => (event::Status::Captured, None),
+            },
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: Key::Named(Named::Delete | Named::Backspace),
+                ..
+            }) if !state.selection.is_empty() => (
+                event::Status::Captured,
+                Some(Message::ShapesRemoved(std::mem::take(&mut state.selection))),
+            ),
+            _ => (event::Status::Ignored, None),
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let palette = theme.extended_palette();
+        let shape_stroke = Stroke::default()
+            .with_width(2.0)
+            .with_color(palette.primary.strong.color);
+        let selection_stroke = Stroke::default()
+            .with_width(2.0)
+            .with_color(palette.background.base.text);
+
+        let document = self.cache.draw(renderer, bounds.size(), |frame| {
+            frame.fill_rectangle(Point::ORIGIN, bounds.size(), palette.background.weak.color);
+
+            for shape in self.shapes {
+                shape.fill_and_stroke(frame, Some(palette.primary.weak.color), shape_stroke);
+            }
+        });
+
+        let mut overlay = Frame::new(renderer, bounds.size());
+
+        for shape in state.selection.iter().filter_map(|&i| self.shapes.get(i)) {
+            shape.fill_and_stroke(&mut overlay, None, selection_stroke);
+        }
+
+        if let [i] = state.selection[..]
+            && let Some(shape) = self.shapes.get(i)
+        {
+            let top = shape.to_canvas(Vector::new(0.0, -shape.size.height / 2.0));
+            let rotate_handle = shape.rotate_handle();
+            overlay.stroke(&Path::line(top, rotate_handle), selection_stroke);
+            overlay.fill(
+                &Path::circle(rotate_handle, HANDLE_SIZE / 2.0),
+                palette.background.base.text,
+            );
+
+            for corner in shape.corners() {
+                let handle = Path::rectangle(
+                    corner - Vector::new(HANDLE_SIZE / 2.0, HANDLE_SIZE / 2.0),
+                    Size::new(HANDLE_SIZE, HANDLE_SIZE),
+                );
+                overlay.fill(&handle, palette.background.base.color);
+                overlay.stroke(&handle, selection_stroke);
+            }
+        }
+
+        if let Some(position) = cursor.position_from(bounds.position()) {
+            match &state.action {
+                Action::Selecting { start } => {
+                    let band = rectangle_around(&[*start, position]);
+                    let band = Path::rectangle(band.position(), band.size());
+                    overlay.fill(&band, palette.primary.base.color.scale_alpha(0.2));
+                    overlay.stroke(&band, shape_stroke.with_width(1.0));
+                }
+                Action::Drawing { start } => {
+                    if let Some(kind) = self.tool.kind() {
+                        Shape::between(kind, *start, position).fill_and_stroke(
+                            &mut overlay,
+                            None,
+                            selection_stroke,
+                        );
+                    }
+                }
+                Action::DrawingPolyline { points } => {
+                    let preview = Path::new(|builder| {
+                        for (i, point) in points.iter().chain([&position]).enumerate() {
+                            if i == 0 {
+                                builder.move_to(*point);
+                            } else {
+                                builder.line_to(*point);
+                            }
+                        }
+                    });
+                    overlay.stroke(&preview, selection_stroke);
+                }
+                _ => {}
+            }
+        }
+
+        vec![document, overlay.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Self::State,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        match state.action {
+            Action::Moving { .. } => return mouse::Interaction::Grabbing,
+            Action::Resizing { .. } | Action::Rotating { .. } => {
+                return mouse::Interaction::Crosshair;
+            }
+            _ => {}
+        }
+
+        let Some(position) = cursor.position_in(bounds) else {
+            return mouse::Interaction::default();
+        };
+
+        match self.tool {
+            Tool::Select => match self.handle_at(&state.selection, position) {
+                Some(_) => mouse::Interaction::Crosshair,
+                None if self.shape_at(position).is_some() => mouse::Interaction::Grab,
+                None => mouse::Interaction::default(),
+            },
+            _ => mouse::Interaction::Crosshair,
+        }
+    }
+}
+
+impl Editor<'_> {
+    fn press(&self, state: &mut State, position: Point) {
+        let shift = state.modifiers.shift();
+
+        state.action = match self.tool {
+            Tool::Select => {
+                if let Some(handle) = self.handle_at(&state.selection, position) {
+                    let index = state.selection[0];
+                    match handle {
+                        Handle::Corner(corner) => Action::Resizing {
+                            index,
+                            fixed: self.shapes[index].corners()[(corner + 2) % 4],
+                        },
+                        Handle::Rotate => Action::Rotating { index },
+                    }
+                } else if let Some(i) = self.shape_at(position) {
+                    if shift {
+                        match state.selection.iter().position(|&s| s == i) {
+                            Some(at) => {
+                                state.selection.remove(at);
+                            }
+                            None => state.selection.push(i),
+                        }
+                    } else if !state.selection.contains(&i) {
+                        state.selection = vec![i];
+                    }
+                    Action::Moving { last: position }
+                } else {
+                    if !shift {
+                        state.selection.clear();
+                    }
+                    Action::Selecting { start: position }
+                }
+            }
+            Tool::Rectangle | Tool::Ellipse => Action::Drawing { start: position },
+            Tool::Polyline => match std::mem::take(&mut state.action) {
+                Action::DrawingPolyline { mut points } => {
+                    points.push(position);
+                    Action::DrawingPolyline { points }
+                }
+                _ => Action::DrawingPolyline {
+                    points: vec![position],
+                },
+            },
+        };
+    }
+
+    fn drag(&self, state: &mut State, position: Point) -> (event::Status, Option<Message>) {
+        match &mut state.action {
+            Action::Moving { last } => {
+                let delta = position - *last;
+                *last = position;
+                (
+                    event::Status::Captured,
+                    Some(Message::ShapesMoved(state.selection.clone(), delta)),
+                )
+            }
+            Action::Resizing { index, fixed } => (
+                event::Status::Captured,
+                self.shapes
+                    .get(*index)
+                    .map(|shape| Message::ShapeChanged(*index, shape.resized(*fixed, position))),
+            ),
+            Action::Rotating { index } => (
+                event::Status::Captured,
+                self.shapes
+                    .get(*index)
+                    .map(|shape| Message::ShapeChanged(*index, shape.rotated_towards(position))),
+            ),
+            Action::Selecting { .. } | Action::Drawing { .. } => (event::Status::Captured, None),
+            Action::DrawingPolyline { .. } | Action::Idle => (event::Status::Ignored, None),
+        }
+    }
+
+    fn release(&self, state: &mut State, position: Point) -> (event::Status, Option<Message>) {
+        match std::mem::take(&mut state.action) {
+            Action::Idle => (event::Status::Ignored, None),
+            // A polyline is finished by Enter, not by releasing the button.
+            action @ Action::DrawingPolyline { .. } => {
+                state.action = action;
+                (event::Status::Ignored, None)
+            }
+            Action::Selecting { start } => {
+                let band = rectangle_around(&[start, position]);
+                let inside = self
+                    .shapes
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, shape)| shape.bounds().is_within(&band))
+                    .map(|(i, _)| i);
+
+                if !state.modifiers.shift() {
+                    state.selection.clear();
+                }
+                for i in inside {
+                    if !state.selection.contains(&i) {
+                        state.selection.push(i);
+                    }
+                }
+                (event::Status::Captured, None)
+            }
+            Action::Drawing { start } => {
+                let Some(kind) = self.tool.kind() else {
+                    return (event::Status::Captured, None);
+                };
+                let shape = Shape::between(kind, start, position);
+                if shape.size.width < MIN_SIZE || shape.size.height < MIN_SIZE {
+                    return (event::Status::Captured, None);
+                }
+
+                state.selection = vec![self.shapes.len()];
+                (event::Status::Captured, Some(Message::ShapeAdded(shape)))
+            }
+            Action::Moving { .. } | Action::Resizing { .. } | Action::Rotating { .. } => {
+                (event::Status::Captured, None)
+            }
+        }
+    }
+}
+
+impl Tool {
+    // The kind of shape drawn by dragging with this tool.
+    fn kind(self) -> Option<Kind> {
+        match self {
+            Tool::Rectangle => Some(Kind::Rectangle),
+            Tool::Ellipse => Some(Kind::Ellipse),
+            Tool::Select | Tool::Polyline => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::FRAC_PI_4;
+
+    fn shape(kind: Kind, center: Point, size: Size, rotation: f32) -> Shape {
+        Shape {
+            kind,
+            center,
+            size,
+            rotation,
+        }
+    }
+
+    #[test]
+    fn rotated_rectangles_contain_the_rotated_box() {
+        // Standing upright, the rectangle is 10 wide and 40 high.
+        let rectangle = shape(
+            Kind::Rectangle,
+            Point::ORIGIN,
+            Size::new(40.0, 10.0),
+            FRAC_PI_2,
+        );
+        assert!(rectangle.contains(Point::new(0.0, 15.0), 0.0));
+        assert!(!rectangle.contains(Point::new(15.0, 0.0), 0.0));
+    }
+
+    #[test]
+    fn ellipses_contain_their_boundary_give_or_take_the_tolerance() {
+        let ellipse = shape(Kind::Ellipse, Point::ORIGIN, Size::new(40.0, 20.0), 0.0);
+        assert!(ellipse.contains(Point::new(20.0, 0.0), 0.0));
+        assert!(!ellipse.contains(Point::new(23.0, 0.0), 0.0));
+        assert!(ellipse.contains(Point::new(23.0, 0.0), TOLERANCE));
+        // Inside the bounds, but outside the ellipse.
+        assert!(!ellipse.contains(Point::new(15.0, 8.0), 0.0));
+    }
+
+    #[test]
+    fn distance_to_segments() {
+        let (a, b) = (Vector::new(0.0, 0.0), Vector::new(10.0, 0.0));
+        assert_eq!(distance_to_segment(Vector::new(5.0, 3.0), a, b), 3.0);
+        assert_eq!(distance_to_segment(Vector::new(13.0, 4.0), a, b), 5.0);
+
+        let point = Vector::new(1.0, 1.0);
+        assert_eq!(
+            distance_to_segment(Vector::new(4.0, 5.0), point, point),
+            5.0
+        );
+    }
+
+    #[test]
+    fn polylines_contain_the_points_near_their_segments() {
+        let polyline = Shape::polyline(&[
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(10.0, 10.0),
+        ]);
+        assert!(polyline.contains(Point::new(5.0, 2.0), TOLERANCE));
+        assert!(!polyline.contains(Point::new(5.0, 5.0), TOLERANCE));
+    }
+
+    #[test]
+    fn rubber_band_selects_the_shapes_within_it() {
+        let shapes = [
+            Shape::between(
+                Kind::Rectangle,
+                Point::new(0.0, 0.0),
+                Point::new(10.0, 10.0),
+            ),
+            Shape::between(
+                Kind::Rectangle,
+                Point::new(50.0, 50.0),
+                Point::new(60.0, 60.0),
+            ),
+            // Its corners stick out of the band when it is rotated.
+            shape(
+                Kind::Rectangle,
+                Point::new(100.0, 0.0),
+                Size::new(20.0, 20.0),
+                FRAC_PI_4,
+            ),
+        ];
+        let cache = Cache::new();
+        let editor = Editor {
+            shapes: &shapes,
+            tool: Tool::Select,
+            cache: &cache,
+        };
+        let mut state = State::default();
+
+        let select = |state: &mut State, start: Point, end: Point| {
+            state.action = Action::Selecting { start };
+            let _ = editor.release(state, end);
+            state.selection.clone()
+        };
+
+        assert_eq!(
+            select(&mut state, Point::new(-5.0, -5.0), Point::new(115.0, 15.0)),
+            [0]
+        );
+        assert_eq!(
+            select(&mut state, Point::new(65.0, 65.0), Point::new(45.0, 45.0)),
+            [1]
+        );
+        assert_eq!(
+            select(&mut state, Point::new(80.0, -20.0), Point::new(120.0, 20.0)),
+            [2]
+        );
+
+        state.modifiers = Modifiers::SHIFT;
+        assert_eq!(
+            select(&mut state, Point::new(-5.0, -5.0), Point::new(15.0, 15.0)),
+            [2, 0]
+        );
+    }
+}
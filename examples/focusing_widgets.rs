@@ -0,0 +1,246 @@
+use iced::{
+    Border, Color, Element, Event, Length, Rectangle, Shadow, Size, Task, Theme,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        graphics::core::event,
+        layout, mouse,
+        renderer::{self, Quad},
+        widget::{
+            Operation, Tree,
+            operation::Focusable,
+            tree::{self, Tag},
+        },
+    },
+    event::Status,
+    keyboard::{self, Key, key::Named},
+    widget::{column, focus_next, focus_previous, row, text, text_input},
+};
+
+fn main() -> iced::Result {
+    iced::application("My App", MyApp::update, MyApp::view)
+        .subscription(MyApp::subscription)
+        .run()
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    NameChanged(String),
+    EmailChanged(String),
+    Pressed(usize),
+    FocusNext,
+    FocusPrevious,
+}
+
+#[derive(Default)]
+struct MyApp {
+    name: String,
+    email: String,
+    counts: [u32; 2],
+}
+
+impl MyApp {
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::NameChanged(s) => self.name = s,
+            Message::EmailChanged(s) => self.email = s,
+            Message::Pressed(index) => self.counts[index] += 1,
+            Message::FocusNext => return focus_next(),
+            Message::FocusPrevious => return focus_previous(),
+        }
+        Task::none()
+    }
+
+    fn view(&self) -> iced::Element<'_, Message> {
+        column![
+            text_input("Name", &self.name).on_input(Message::NameChanged),
+            row![
+                MyWidget::new(Message::Pressed(0)),
+                text(format!("Pressed {} times", self.counts[0])),
+            ]
+            .spacing(10),
+            text_input("Email", &self.email).on_input(Message::EmailChanged),
+            row![
+                MyWidget::new(Message::Pressed(1)),
+                text(format!("Pressed {} times", self.counts[1])),
+            ]
+            .spacing(10),
+            text("Press Tab or Shift+Tab to move the focus, and Space to press a focused widget"),
+        ]
+        .spacing(20)
+        .padding(20)
+        .into()
+    }
+
+    fn subscription(&self) -> iced::Subscription<Message> {
+        iced::event::listen_with(|event, status, _| match (event, status) {
+            (
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: Key::Named(Named::Tab),
+                    modifiers,
+                    ..
+                }),
+                Status::Ignored,
+            ) => Some(if modifiers.shift() {
+                Message::FocusPrevious
+            } else {
+                Message::FocusNext
+            }),
+            _ => None,
+        })
+    }
+}
+
+#[derive(Default)]
+struct State {
+    is_focused: bool,
+}
+
+impl Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+struct MyWidget<Message> {
+    on_press: Message,
+}
+
+impl<Message> MyWidget<Message> {
+    fn new(on_press: Message) -> Self {
+        Self { on_press }
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Theme, Renderer> for MyWidget<Message>
+where
+    Message: Clone,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        _limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(Size::new(100.0, 30.0))
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    radius: 5.0.into(),
+                    ..Border::default()
+                },
+                shadow: Shadow::default(),
+            },
+            Color::from_rgb(0.0, 0.2, 0.4),
+        );
+
+        if state.is_focused {
+            renderer.fill_quad(
+                Quad {
+                    bounds: bounds.expand(3.0),
+                    border: Border {
+                        color: theme.palette().primary,
+                        width: 2.0,
+                        radius: 8.0.into(),
+                    },
+                    shadow: Shadow::default(),
+                },
+                Color::TRANSPARENT,
+            );
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        operation.focusable(state, None);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                state.is_focused = cursor.is_over(layout.bounds());
+                if state.is_focused {
+                    shell.publish(self.on_press.clone());
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: Key::Named(Named::Space | Named::Enter),
+                ..
+            }) if state.is_focused => {
+                shell.publish(self.on_press.clone());
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<MyWidget<Message>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: iced::advanced::Renderer,
+{
+    fn from(widget: MyWidget<Message>) -> Self {
+        Self::new(widget)
+    }
+}
@@ -0,0 +1,763 @@
+use iced::{
+    Alignment, Border, Color, Element, Event, Length, Point, Rectangle, Shadow, Size, Theme,
+    Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        graphics::core::event,
+        layout, mouse, overlay,
+        renderer::{self, Quad},
+        widget::{Operation, Tree},
+    },
+    widget::{button, column, container, radio, row, text},
+};
+
+fn main() -> iced::Result {
+    iced::run("My App", MyApp::update, MyApp::view)
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    AlignXSelected(Alignment),
+    AlignYSelected(Alignment),
+    Pressed(&'static str),
+}
+
+struct MyApp {
+    align_x: Alignment,
+    align_y: Alignment,
+    pressed: Option<&'static str>,
+}
+
+impl Default for MyApp {
+    fn default() -> Self {
+        Self {
+            align_x: Alignment::Center,
+            align_y: Alignment::Center,
+            pressed: None,
+        }
+    }
+}
+
+impl MyApp {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::AlignXSelected(align) => self.align_x = align,
+            Message::AlignYSelected(align) => self.align_y = align,
+            Message::Pressed(label) => self.pressed = Some(label),
+        }
+    }
+
+    fn view(&self) -> iced::Element<'_, Message> {
+        let align_x = Some(self.align_x);
+        let align_y = Some(self.align_y);
+
+        let columns = [
+            Length::Fixed(120.0),
+            Length::Fill,
+            Length::FillPortion(2),
+            Length::Shrink,
+        ];
+        let rows = [
+            Length::Fixed(50.0),
+            Length::Fill,
+            Length::Fill,
+            Length::Shrink,
+        ];
+
+        let grid = Grid::new(columns, rows)
+            .spacing(10.0)
+            .outline_cells(true)
+            .push(cell(0, 0, boxed("Header spanning 4 columns")).span(1, 4))
+            .push(cell(1, 0, boxed("Sidebar spanning 2 rows")).span(2, 1))
+            .push(cell(1, 1, boxed("Fill")))
+            .push(cell(1, 2, boxed("FillPortion(2)")))
+            .push(
+                cell(1, 3, button("Shrink").on_press(Message::Pressed("Shrink")))
+                    .align_x(self.align_x)
+                    .align_y(self.align_y),
+            )
+            .push(
+                cell(
+                    2,
+                    1,
+                    button("Spanning").on_press(Message::Pressed("Spanning")),
+                )
+                .span(1, 2)
+                .align_x(self.align_x)
+                .align_y(self.align_y),
+            )
+            .push(cell(2, 3, text("A shrinking column")))
+            .push(
+                cell(
+                    3,
+                    0,
+                    text(match self.pressed {
+                        Some(label) => format!("{} pressed", label),
+                        None => "Resize the window to resize the Fill tracks".into(),
+                    }),
+                )
+                .span(1, 3)
+                .align_y(Alignment::Center),
+            )
+            .push(cell(
+                3,
+                3,
+                button("Footer").on_press(Message::Pressed("Footer")),
+            ));
+
+        column![
+            row![
+                text("Horizontal:"),
+                radio("Start", Alignment::Start, align_x, Message::AlignXSelected),
+                radio(
+                    "Center",
+                    Alignment::Center,
+                    align_x,
+                    Message::AlignXSelected
+                ),
+                radio("End", Alignment::End, align_x, Message::AlignXSelected),
+            ]
+            .spacing(10),
+            row![
+                text("Vertical:"),
+                radio("Start", Alignment::Start, align_y, Message::AlignYSelected),
+                radio(
+                    "Center",
+                    Alignment::Center,
+                    align_y,
+                    Message::AlignYSelected
+                ),
+                radio("End", Alignment::End, align_y, Message::AlignYSelected),
+            ]
+            .spacing(10),
+            grid,
+        ]
+        .spacing(10)
+        .padding(10)
+        .into()
+    }
+}
+
+fn boxed(label: &str) -> Element<'_, Message> {
+    container(text(label))
+        .padding(10)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .style(container::bordered_box)
+        .into()
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Placement {
+    row: usize,
+    column: usize,
+    row_span: usize,
+    column_span: usize,
+    align_x: Alignment,
+    align_y: Alignment,
+}
+
+struct Cell<'a, Message, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    placement: Placement,
+}
+
+fn cell<'a, Message, Renderer>(
+    row: usize,
+    column: usize,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Cell<'a, Message, Renderer> {
+    Cell {
+        content: content.into(),
+        placement: Placement {
+            row,
+            column,
+            row_span: 1,
+            column_span: 1,
+            align_x: Alignment::Start,
+            align_y: Alignment::Start,
+        },
+    }
+}
+
+impl<Message, Renderer> Cell<'_, Message, Renderer> {
+    fn span(mut self, rows: usize, columns: usize) -> Self {
+        self.placement.row_span = rows;
+        self.placement.column_span = columns;
+        self
+    }
+
+    fn align_x(mut self, align: Alignment) -> Self {
+        self.placement.align_x = align;
+        self
+    }
+
+    fn align_y(mut self, align: Alignment) -> Self {
+        self.placement.align_y = align;
+        self
+    }
+}
+
+struct Grid<'a, Message, Renderer> {
+    columns: Vec<Length>,
+    rows: Vec<Length>,
+    spacing: f32,
+    outline_cells: bool,
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    placements: Vec<Placement>,
+}
+
+impl<'a, Message, Renderer> Grid<'a, Message, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn new(
+        columns: impl IntoIterator<Item = Length>,
+        rows: impl IntoIterator<Item = Length>,
+    ) -> Self {
+        Self {
+            columns: columns.into_iter().collect(),
+            rows: rows.into_iter().collect(),
+            spacing: 0.0,
+            outline_cells: false,
+            children: Vec::new(),
+            placements: Vec::new(),
+        }
+    }
+
+    fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    fn outline_cells(mut self, outline_cells: bool) -> Self {
+        self.outline_cells = outline_cells;
+        self
+    }
+
+    // A cell outside of the grid is moved into its last row or column, and spans are cut at the
+    // edges of the grid. A grid without rows or columns has no place for cells at all.
+    fn push(mut self, cell: Cell<'a, Message, Renderer>) -> Self {
+        if self.rows.is_empty() || self.columns.is_empty() {
+            return self;
+        }
+
+        let mut placement = cell.placement;
+        placement.row = placement.row.min(self.rows.len() - 1);
+        placement.column = placement.column.min(self.columns.len() - 1);
+        placement.row_span = placement.row_span.clamp(1, self.rows.len() - placement.row);
+        placement.column_span = placement
+            .column_span
+            .clamp(1, self.columns.len() - placement.column);
+
+        self.children.push(cell.content);
+        self.placements.push(placement);
+        self
+    }
+}
+
+fn fills(tracks: &[Length]) -> Length {
+    if tracks.iter().any(|track| track.fill_factor() > 0) {
+        Length::Fill
+    } else {
+        Length::Shrink
+    }
+}
+
+// Returns the size of every track along one axis. Each child is given by its first track, the
+// number of tracks it spans and its own size along the axis.
+fn track_sizes(
+    tracks: &[Length],
+    spacing: f32,
+    max: f32,
+    children: impl Iterator<Item = (usize, usize, f32)>,
+) -> Vec<f32> {
+    // Fill tracks share the available space, unless the space is unbounded. Then they fit their
+    // children like shrinking tracks.
+    let is_filling = |track: &Length| max.is_finite() && track.fill_factor() > 0;
+    let is_shrinking = |track: &Length| !is_filling(track) && !matches!(track, Length::Fixed(_));
+
+    let mut sizes: Vec<f32> = tracks
+        .iter()
+        .map(|track| match track {
+            Length::Fixed(size) => *size,
+            _ => 0.0,
+        })
+        .collect();
+
+    // Children spanning a single track go first, so that a spanning child only adds the space that
+    // its tracks still miss. A child spanning a Fill track leaves the other tracks alone.
+    let mut children: Vec<_> = children.collect();
+    children.sort_by_key(|(_, span, _)| *span);
+
+    for (start, span, size) in children {
+        let tracks_spanned = start..start + span;
+        if tracks[tracks_spanned.clone()].iter().any(is_filling) {
+            continue;
+        }
+
+        let current =
+            sizes[tracks_spanned.clone()].iter().sum::<f32>() + spacing * (span - 1) as f32;
+        let shrinking: Vec<_> = tracks_spanned
+            .filter(|&i| is_shrinking(&tracks[i]))
+            .collect();
+        if size > current && !shrinking.is_empty() {
+            let extra = (size - current) / shrinking.len() as f32;
+            for i in shrinking {
+                sizes[i] += extra;
+            }
+        }
+    }
+
+    let portions: u16 = tracks
+        .iter()
+        .filter(|track| is_filling(track))
+        .map(Length::fill_factor)
+        .sum();
+    if portions > 0 {
+        let used = tracks
+            .iter()
+            .zip(&sizes)
+            .filter(|(track, _)| !is_filling(track))
+            .map(|(_, size)| size)
+            .sum::<f32>()
+            + spacing * (tracks.len() - 1) as f32;
+        let free = (max - used).max(0.0);
+
+        for (track, size) in tracks.iter().zip(&mut sizes) {
+            if is_filling(track) {
+                *size = free * track.fill_factor() as f32 / portions as f32;
+            }
+        }
+    }
+
+    sizes
+}
+
+fn offsets(sizes: &[f32], spacing: f32) -> Vec<f32> {
+    sizes
+        .iter()
+        .scan(0.0, |offset, size| {
+            let current = *offset;
+            *offset += size + spacing;
+            Some(current)
+        })
+        .collect()
+}
+
+fn span_size(sizes: &[f32], spacing: f32, start: usize, span: usize) -> f32 {
+    sizes[start..start + span].iter().sum::<f32>() + spacing * (span - 1) as f32
+}
+
+fn total_size(sizes: &[f32], spacing: f32) -> f32 {
+    if sizes.is_empty() {
+        0.0
+    } else {
+        span_size(sizes, spacing, 0, sizes.len())
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Theme, Renderer> for Grid<'_, Message, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: fills(&self.columns),
+            height: fills(&self.rows),
+        }
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let max = limits.max();
+
+        // The widths of the columns come from the widths the children want.
+        let widths: Vec<_> = self
+            .children
+            .iter()
+            .zip(&mut tree.children)
+            .map(|(child, tree)| {
+                child
+                    .as_widget()
+                    .layout(tree, renderer, &limits.loose())
+                    .size()
+                    .width
+            })
+            .collect();
+        let column_sizes = track_sizes(
+            &self.columns,
+            self.spacing,
+            max.width,
+            self.placements
+                .iter()
+                .zip(widths)
+                .map(|(p, width)| (p.column, p.column_span, width)),
+        );
+
+        // The heights of the rows come from the heights the children want within their columns.
+        let heights: Vec<_> = self
+            .children
+            .iter()
+            .zip(&mut tree.children)
+            .zip(&self.placements)
+            .map(|((child, tree), p)| {
+                let width = span_size(&column_sizes, self.spacing, p.column, p.column_span);
+                let limits = layout::Limits::new(Size::ZERO, Size::new(width, max.height));
+                child
+                    .as_widget()
+                    .layout(tree, renderer, &limits)
+                    .size()
+                    .height
+            })
+            .collect();
+        let row_sizes = track_sizes(
+            &self.rows,
+            self.spacing,
+            max.height,
+            self.placements
+                .iter()
+                .zip(heights)
+                .map(|(p, height)| (p.row, p.row_span, height)),
+        );
+
+        // Every child is laid out within its cell and aligned there.
+        let column_offsets = offsets(&column_sizes, self.spacing);
+        let row_offsets = offsets(&row_sizes, self.spacing);
+        let cells: Vec<_> = self
+            .children
+            .iter()
+            .zip(&mut tree.children)
+            .zip(&self.placements)
+            .map(|((child, tree), p)| {
+                let cell_size = Size::new(
+                    span_size(&column_sizes, self.spacing, p.column, p.column_span),
+                    span_size(&row_sizes, self.spacing, p.row, p.row_span),
+                );
+                let node = child
+                    .as_widget()
+                    .layout(tree, renderer, &layout::Limits::new(Size::ZERO, cell_size))
+                    .align(p.align_x, p.align_y, cell_size);
+
+                layout::Node::with_children(cell_size, vec![node])
+                    .move_to(Point::new(column_offsets[p.column], row_offsets[p.row]))
+            })
+            .collect();
+
+        let size = self.size();
+        let content = Size::new(
+            total_size(&column_sizes, self.spacing),
+            total_size(&row_sizes, self.spacing),
+        );
+
+        layout::Node::with_children(limits.resolve(size.width, size.height, content), cells)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if self.outline_cells {
+            for cell in layout.children() {
+                renderer.fill_quad(
+                    Quad {
+                        bounds: cell.bounds(),
+                        border: Border {
+                            color: theme.extended_palette().background.strong.color,
+                            width: 1.0,
+                            radius: 0.0.into(),
+                        },
+                        shadow: Shadow::default(),
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+        }
+
+        // Each cell holds exactly one child.
+        for ((child, tree), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children().flat_map(|cell| cell.children()))
+        {
+            child
+                .as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            for ((child, tree), layout) in self
+                .children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children().flat_map(|cell| cell.children()))
+            {
+                child.as_widget().operate(tree, layout, renderer, operation);
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children().flat_map(|cell| cell.children()))
+            .map(|((child, tree), layout)| {
+                child.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children().flat_map(|cell| cell.children()))
+            .map(|((child, tree), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let children = self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children().flat_map(|cell| cell.children()))
+            .filter_map(|((child, tree), layout)| {
+                child
+                    .as_widget_mut()
+                    .overlay(tree, layout, renderer, translation)
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message, Renderer> From<Grid<'a, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(widget: Grid<'a, Message, Renderer>) -> Self {
+        Self::new(widget)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::widget::Space;
+
+    #[test]
+    fn tracks_get_their_sizes() {
+        let tracks = [
+            Length::Fixed(50.0),
+            Length::Fill,
+            Length::FillPortion(2),
+            Length::Shrink,
+        ];
+        let sizes = track_sizes(&tracks, 10.0, 420.0, [(3, 1, 40.0)].into_iter());
+        assert_eq!(sizes, [50.0, 100.0, 200.0, 40.0]);
+    }
+
+    #[test]
+    fn a_spanning_child_only_adds_the_missing_space() {
+        let tracks = [Length::Fixed(50.0), Length::Shrink, Length::Shrink];
+        let children = [(0, 3, 200.0), (1, 1, 30.0)];
+        let sizes = track_sizes(&tracks, 10.0, 500.0, children.into_iter());
+        assert_eq!(sizes, [50.0, 80.0, 50.0]);
+
+        let tracks = [Length::Shrink, Length::Shrink];
+        let children = [(0, 2, 50.0), (0, 1, 60.0)];
+        let sizes = track_sizes(&tracks, 10.0, 500.0, children.into_iter());
+        assert_eq!(sizes, [60.0, 0.0]);
+    }
+
+    #[test]
+    fn a_child_spanning_a_fill_track_leaves_the_other_tracks_alone() {
+        let tracks = [Length::Shrink, Length::Fill];
+        let sizes = track_sizes(&tracks, 0.0, 100.0, [(0, 2, 300.0)].into_iter());
+        assert_eq!(sizes, [0.0, 100.0]);
+    }
+
+    #[test]
+    fn fill_tracks_fit_their_children_without_a_bound() {
+        let tracks = [Length::Fill, Length::FillPortion(2)];
+        let children = [(0, 1, 30.0), (1, 1, 40.0)];
+        let sizes = track_sizes(&tracks, 10.0, f32::INFINITY, children.into_iter());
+        assert_eq!(sizes, [30.0, 40.0]);
+    }
+
+    #[test]
+    fn push_clamps_the_spans_to_the_grid() {
+        let grid: Grid<'_, (), ()> = Grid::new([Length::Fill; 2], [Length::Fill; 3])
+            .push(cell(1, 1, Space::new(0, 0)).span(5, 5))
+            .push(cell(0, 0, Space::new(0, 0)).span(0, 0));
+
+        let spans: Vec<_> = grid
+            .placements
+            .iter()
+            .map(|placement| (placement.row_span, placement.column_span))
+            .collect();
+        assert_eq!(spans, [(2, 1), (1, 1)]);
+    }
+
+    #[test]
+    fn push_moves_cells_into_the_grid() {
+        let grid: Grid<'_, (), ()> = Grid::new([Length::Fill; 2], [Length::Fill; 3])
+            .push(cell(3, 0, Space::new(0, 0)))
+            .push(cell(0, 5, Space::new(0, 0)).span(2, 2));
+
+        let cells: Vec<_> = grid
+            .placements
+            .iter()
+            .map(|p| (p.row, p.column, p.row_span, p.column_span))
+            .collect();
+        assert_eq!(cells, [(2, 0, 1, 1), (0, 1, 2, 1)]);
+
+        let empty: Grid<'_, (), ()> =
+            Grid::new([], [Length::Fill]).push(cell(0, 0, Space::new(0, 0)));
+        assert!(empty.children.is_empty());
+    }
+
+    // Lays out the grid within `limits`, and returns its size and the bounds of every cell
+    // together with the bounds of its child within the cell.
+    fn layout_grid(
+        grid: Grid<'_, (), ()>,
+        limits: layout::Limits,
+    ) -> (Size, Vec<(Rectangle, Rectangle)>) {
+        let grid: Element<'_, (), Theme, ()> = grid.into();
+        let mut tree = Tree::new(&grid);
+        let node = grid.as_widget().layout(&mut tree, &(), &limits);
+        let cells = node
+            .children()
+            .iter()
+            .map(|cell| (cell.bounds(), cell.children()[0].bounds()))
+            .collect();
+        (node.size(), cells)
+    }
+
+    #[test]
+    fn children_are_placed_and_aligned_within_their_cells() {
+        let grid = Grid::new(
+            [Length::Fixed(100.0), Length::Fixed(50.0)],
+            [Length::Fixed(40.0), Length::Fixed(30.0)],
+        )
+        .spacing(10.0)
+        .push(
+            cell(0, 0, Space::new(20.0, 10.0))
+                .span(2, 1)
+                .align_x(Alignment::Center)
+                .align_y(Alignment::End),
+        )
+        .push(cell(0, 1, Space::new(20.0, 10.0)).span(1, 5))
+        .push(cell(1, 1, Space::new(Length::Fill, Length::Fill)));
+
+        let limits = layout::Limits::new(Size::ZERO, Size::new(500.0, 500.0));
+        let (size, cells) = layout_grid(grid, limits);
+        assert_eq!(size, Size::new(160.0, 80.0));
+        assert_eq!(
+            cells,
+            [
+                (
+                    Rectangle::new(Point::new(0.0, 0.0), Size::new(100.0, 80.0)),
+                    Rectangle::new(Point::new(40.0, 70.0), Size::new(20.0, 10.0)),
+                ),
+                (
+                    Rectangle::new(Point::new(110.0, 0.0), Size::new(50.0, 40.0)),
+                    Rectangle::new(Point::new(0.0, 0.0), Size::new(20.0, 10.0)),
+                ),
+                (
+                    Rectangle::new(Point::new(110.0, 50.0), Size::new(50.0, 30.0)),
+                    Rectangle::new(Point::new(0.0, 0.0), Size::new(50.0, 30.0)),
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn fill_tracks_share_the_space_of_the_limits() {
+        let grid = Grid::new([Length::Fill, Length::FillPortion(3)], [Length::Shrink])
+            .spacing(10.0)
+            .push(cell(0, 0, Space::new(20.0, 10.0)))
+            .push(cell(0, 1, Space::new(20.0, 10.0)));
+
+        let limits = layout::Limits::new(Size::ZERO, Size::new(410.0, 500.0));
+        let (size, cells) = layout_grid(grid, limits);
+        assert_eq!(size, Size::new(410.0, 10.0));
+        let cells: Vec<_> = cells.iter().map(|(cell, _)| *cell).collect();
+        assert_eq!(
+            cells,
+            [
+                Rectangle::new(Point::new(0.0, 0.0), Size::new(100.0, 10.0)),
+                Rectangle::new(Point::new(110.0, 0.0), Size::new(300.0, 10.0)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use iced::{
+    Task,
+    widget::{button, column, container, row, text, text_input},
+};
+use iced_tutorial::{
+    modal::modal,
+    router::{Guard, Navigation, Page, Routed, Router},
+};
+use std::time::Duration;
+
+fn main() -> iced::Result {
+    iced::application("My App", MyApp::update, MyApp::view).run_with(MyApp::new)
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Confirm,
+    Cancel,
+    Page(Routed),
+}
+
+struct MyApp {
+    router: Router,
+}
+
+impl MyApp {
+    fn new() -> (Self, Task<Message>) {
+        let (router, task) = Router::new(PageA::new());
+        (Self { router }, task.map(Message::Page))
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        let task = match message {
+            Message::Confirm => self.router.confirm(),
+            Message::Cancel => {
+                self.router.cancel();
+                Task::none()
+            }
+            Message::Page(message) => self.router.update(message).unwrap_or_else(|stale| {
+                eprintln!("{}", stale);
+                Task::none()
+            }),
+        };
+        task.map(Message::Page)
+    }
+
+    fn view(&self) -> iced::Element<'_, Message> {
+        let page = self.router.view().map(Message::Page);
+        match self.router.pending_confirmation() {
+            Some(question) => {
+                let dialog = container(column![
+                    text(question),
+                    row![
+                        button("Leave").on_press(Message::Confirm),
+                        button("Stay").on_press(Message::Cancel),
+                    ]
+                    .spacing(10),
+                ])
+                .padding(20)
+                .style(container::rounded_box);
+                modal(page, dialog, Message::Cancel)
+            }
+            None => page,
+        }
+    }
+}
+
+// Page A
+#[derive(Debug, Clone)]
+enum PageAMessage {
+    ButtonPressed,
+}
+
+struct PageA;
+
+impl PageA {
+    fn new() -> Self {
+        Self
+    }
+}
+
+impl Page for PageA {
+    type Message = PageAMessage;
+
+    fn update(&mut self, message: PageAMessage) -> (Navigation, Task<PageAMessage>) {
+        match message {
+            PageAMessage::ButtonPressed => (Navigation::GoTo(PageB::new().into()), Task::none()),
+        }
+    }
+
+    fn view(&self) -> iced::Element<'_, PageAMessage> {
+        column![
+            text("Home"),
+            button("Edit profile").on_press(PageAMessage::ButtonPressed),
+        ]
+        .into()
+    }
+}
+
+// Page B
+#[derive(Debug, Clone)]
+enum PageBMessage {
+    TextChanged(String),
+    SaveButtonPressed,
+    Saved(String),
+    BackButtonPressed,
+}
+
+struct PageB {
+    name: String,
+    saved_name: String,
+    saving: bool,
+}
+
+impl PageB {
+    fn new() -> Self {
+        Self {
+            name: String::new(),
+            saved_name: String::new(),
+            saving: false,
+        }
+    }
+}
+
+impl Page for PageB {
+    type Message = PageBMessage;
+
+    fn update(&mut self, message: PageBMessage) -> (Navigation, Task<PageBMessage>) {
+        match message {
+            PageBMessage::TextChanged(s) => self.name = s,
+            PageBMessage::SaveButtonPressed => {
+                self.saving = true;
+                // Text typed while saving is not part of what is saved.
+                let name = self.name.clone();
+                let save = async move {
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                    name
+                };
+                let task = Task::perform(save, PageBMessage::Saved);
+                return (Navigation::None, task);
+            }
+            PageBMessage::Saved(name) => {
+                self.saving = false;
+                self.saved_name = name;
+            }
+            PageBMessage::BackButtonPressed => return (Navigation::Back, Task::none()),
+        }
+        (Navigation::None, Task::none())
+    }
+
+    fn view(&self) -> iced::Element<'_, PageBMessage> {
+        let status = if self.saving {
+            "Saving..."
+        } else if self.name != self.saved_name {
+            "Unsaved changes"
+        } else {
+            "Saved"
+        };
+
+        column![
+            text_input("Name", &self.name).on_input(PageBMessage::TextChanged),
+            text(status),
+            row![
+                button("Back").on_press(PageBMessage::BackButtonPressed),
+                button("Save").on_press(PageBMessage::SaveButtonPressed),
+            ],
+        ]
+        .into()
+    }
+
+    fn guard(&self) -> Guard {
+        if self.saving {
+            Guard::Deny
+        } else if self.name != self.saved_name {
+            Guard::Confirm("Discard the unsaved changes?".into())
+        } else {
+            Guard::Allow
+        }
+    }
+}
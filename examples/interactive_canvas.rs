@@ -0,0 +1,235 @@
+use iced::{
+    Length, Point, Rectangle, Renderer, Theme,
+    keyboard::{self, Key, key::Named},
+    mouse,
+    widget::{
+        Canvas, button,
+        canvas::{Cache, Event, Frame, Geometry, Path, Program, Stroke, event},
+        column, row, text,
+    },
+};
+
+fn main() -> iced::Result {
+    iced::run("My App", MyApp::update, MyApp::view)
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    PointAdded(Point),
+    PointMoved(usize, Point),
+    PointRemoved(usize),
+    Clear,
+}
+
+// The app owns the document, which is the list of points.
+#[derive(Default)]
+struct MyApp {
+    points: Vec<Point>,
+    cache: Cache,
+}
+
+impl MyApp {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::PointAdded(point) => self.points.push(point),
+            Message::PointMoved(i, point) => {
+                if let Some(p) = self.points.get_mut(i) {
+                    *p = point;
+                }
+            }
+            Message::PointRemoved(i) => {
+                if i < self.points.len() {
+                    self.points.remove(i);
+                }
+            }
+            Message::Clear => self.points.clear(),
+        }
+        self.cache.clear();
+    }
+
+    fn view(&self) -> iced::Element<'_, Message> {
+        column![
+            row![
+                button("Clear").on_press(Message::Clear),
+                text(format!(
+                    "{} points. Click to add a point, drag to move it, and press Delete to remove it",
+                    self.points.len()
+                )),
+            ]
+            .spacing(10)
+            .align_y(iced::Alignment::Center),
+            Canvas::new(Editor {
+                points: &self.points,
+                cache: &self.cache,
+            })
+            .width(Length::Fill)
+            .height(Length::Fill),
+        ]
+        .spacing(10)
+        .padding(10)
+        .into()
+    }
+}
+
+const RADIUS: f32 = 6.0;
+
+struct Editor<'a> {
+    points: &'a [Point],
+    cache: &'a Cache,
+}
+
+impl Editor<'_> {
+    // Returns the point under the cursor, if any.
+    fn point_at(&self, position: Point) -> Option<usize> {
+        self.points
+            .iter()
+            .position(|point| point.distance(position) <= RADIUS + 2.0)
+    }
+}
+
+// The interaction lives in the canvas, and only the changes to the document become messages.
+#[derive(Default)]
+struct State {
+    selected: Option<usize>,
+    is_dragging: bool,
+}
+
+impl Program<Message> for Editor<'_> {
+    type State = State;
+
+    fn update(
+        &self,
+        state: &mut Self::State,
+        event: Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> (event::Status, Option<Message>) {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    return (event::Status::Ignored, None);
+                };
+
+                match self.point_at(position) {
+                    Some(i) => {
+                        state.selected = Some(i);
+                        state.is_dragging = true;
+                        (event::Status::Captured, None)
+                    }
+                    None => {
+                        state.selected = Some(self.points.len());
+                        state.is_dragging = true;
+                        (event::Status::Captured, Some(Message::PointAdded(position)))
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) if state.is_dragging => {
+                let (Some(i), Some(position)) =
+                    (state.selected, cursor.position_from(bounds.position()))
+                else {
+                    return (event::Status::Ignored, None);
+                };
+                let position = Point::new(
+                    position.x.clamp(0.0, bounds.width),
+                    position.y.clamp(0.0, bounds.height),
+                );
+                (
+                    event::Status::Captured,
+                    Some(Message::PointMoved(i, position)),
+                )
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if state.is_dragging =>
+            {
+                state.is_dragging = false;
+                (event::Status::Captured, None)
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: Key::Named(Named::Delete | Named::Backspace),
+                ..
+            }) => match state.selected.take() {
+                Some(i) if i < self.points.len() => {
+                    state.is_dragging = false;
+                    (event::Status::Captured, Some(Message::PointRemoved(i)))
+                }
+                _ => (event::Status::Ignored, None),
+            },
+            _ => (event::Status::Ignored, None),
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let palette = theme.extended_palette();
+
+        // The document only changes by messages, and the app clears the cache then.
+        let document = self.cache.draw(renderer, bounds.size(), |frame| {
+            frame.fill_rectangle(Point::ORIGIN, bounds.size(), palette.background.weak.color);
+
+            let polyline = Path::new(|builder| {
+                for (i, point) in self.points.iter().enumerate() {
+                    if i == 0 {
+                        builder.move_to(*point);
+                    } else {
+                        builder.line_to(*point);
+                    }
+                }
+            });
+            frame.stroke(
+                &polyline,
+                Stroke::default()
+                    .with_width(2.0)
+                    .with_color(palette.background.strong.color),
+            );
+
+            for point in self.points {
+                frame.fill(&Path::circle(*point, RADIUS), palette.primary.base.color);
+            }
+        });
+
+        // The selection and the hovered point change without messages, so they are drawn every time.
+        let mut interaction = Frame::new(renderer, bounds.size());
+        let hovered = cursor.position_in(bounds).and_then(|p| self.point_at(p));
+
+        for (i, color) in [
+            (hovered, palette.primary.weak.color),
+            (state.selected, palette.primary.strong.color),
+        ] {
+            if let Some(point) = i.and_then(|i| self.points.get(i)) {
+                interaction.stroke(
+                    &Path::circle(*point, RADIUS + 4.0),
+                    Stroke::default().with_width(2.0).with_color(color),
+                );
+            }
+        }
+
+        vec![document, interaction.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Self::State,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        if state.is_dragging {
+            mouse::Interaction::Grabbing
+        } else if cursor
+            .position_in(bounds)
+            .and_then(|p| self.point_at(p))
+            .is_some()
+        {
+            mouse::Interaction::Grab
+        } else if cursor.is_over(bounds) {
+            mouse::Interaction::Crosshair
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
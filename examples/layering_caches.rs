@@ -0,0 +1,233 @@
+use std::cell::Cell;
+
+use iced::{
+    Length, Pixels, Point, Rectangle, Renderer, Size, Theme, Vector, mouse,
+    widget::{
+        Canvas, button,
+        canvas::{self, Cache, Frame, Geometry, Path, Program, Stroke},
+        column, row, slider, text,
+    },
+};
+
+fn main() -> iced::Result {
+    iced::run("My App", MyApp::update, MyApp::view)
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    GridSpacingChanged(f32),
+    ValueAdded,
+    ValueRemoved,
+    AllCleared,
+}
+
+struct MyApp {
+    grid_spacing: f32,
+    values: Vec<f32>,
+    // One cache for each layer that does not change every frame.
+    background: Cache,
+    data: Cache,
+    redraws: Redraws,
+}
+
+// How many times each layer was actually drawn. `draw` only borrows the app, so the counters
+// are cells.
+#[derive(Default)]
+struct Redraws {
+    background: Cell<usize>,
+    data: Cell<usize>,
+    overlay: Cell<usize>,
+}
+
+fn increment(counter: &Cell<usize>) {
+    counter.set(counter.get() + 1);
+}
+
+impl Default for MyApp {
+    fn default() -> Self {
+        Self {
+            grid_spacing: 40.0,
+            values: (0..10).map(value).collect(),
+            background: Cache::new(),
+            data: Cache::new(),
+            redraws: Redraws::default(),
+        }
+    }
+}
+
+// A made-up measurement between 0 and 1.
+fn value(i: usize) -> f32 {
+    0.5 + 0.4 * (i as f32 * 1.3).sin()
+}
+
+impl MyApp {
+    fn update(&mut self, message: Message) {
+        // Each message clears only the layers that depend on what it changes.
+        match message {
+            Message::GridSpacingChanged(grid_spacing) => {
+                self.grid_spacing = grid_spacing;
+                self.background.clear();
+            }
+            Message::ValueAdded => {
+                self.values.push(value(self.values.len()));
+                self.data.clear();
+            }
+            Message::ValueRemoved => {
+                self.values.pop();
+                self.data.clear();
+            }
+            Message::AllCleared => {
+                self.background.clear();
+                self.data.clear();
+            }
+        }
+    }
+
+    fn view(&self) -> iced::Element<'_, Message> {
+        column![
+            row![
+                text(format!("Grid spacing: {}", self.grid_spacing)),
+                slider(20.0..=100.0, self.grid_spacing, Message::GridSpacingChanged).width(200),
+                button("Add value").on_press(Message::ValueAdded),
+                button("Remove value").on_press(Message::ValueRemoved),
+                button("Clear all caches").on_press(Message::AllCleared),
+            ]
+            .spacing(10)
+            .align_y(iced::Alignment::Center),
+            Canvas::new(self).width(Length::Fill).height(Length::Fill),
+        ]
+        .spacing(10)
+        .padding(10)
+        .into()
+    }
+
+    // The position of each value in a canvas of the given size.
+    fn points(&self, size: Size) -> impl Iterator<Item = Point> + '_ {
+        let step = size.width / (self.values.len().max(2) - 1) as f32;
+
+        self.values
+            .iter()
+            .enumerate()
+            .map(move |(i, value)| Point::new(i as f32 * step, size.height * (1.0 - value)))
+    }
+}
+
+impl Program<Message> for MyApp {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let palette = theme.extended_palette();
+
+        // The closures only run when their cache was cleared or the size of the canvas changed.
+        let background = self.background.draw(renderer, bounds.size(), |frame| {
+            increment(&self.redraws.background);
+
+            frame.fill_rectangle(Point::ORIGIN, bounds.size(), palette.background.weak.color);
+
+            let grid = Path::new(|builder| {
+                let mut x = 0.0;
+                while x <= bounds.width {
+                    builder.move_to(Point::new(x, 0.0));
+                    builder.line_to(Point::new(x, bounds.height));
+                    x += self.grid_spacing;
+                }
+
+                let mut y = 0.0;
+                while y <= bounds.height {
+                    builder.move_to(Point::new(0.0, y));
+                    builder.line_to(Point::new(bounds.width, y));
+                    y += self.grid_spacing;
+                }
+            });
+            frame.stroke(
+                &grid,
+                Stroke::default().with_color(palette.background.strong.color),
+            );
+        });
+
+        let data = self.data.draw(renderer, bounds.size(), |frame| {
+            increment(&self.redraws.data);
+
+            let line = Path::new(|builder| {
+                for (i, point) in self.points(bounds.size()).enumerate() {
+                    if i == 0 {
+                        builder.move_to(point);
+                    } else {
+                        builder.line_to(point);
+                    }
+                }
+            });
+            frame.stroke(
+                &line,
+                Stroke::default()
+                    .with_width(2.0)
+                    .with_color(palette.primary.strong.color),
+            );
+
+            for point in self.points(bounds.size()) {
+                frame.fill(&Path::circle(point, 4.0), palette.primary.base.color);
+            }
+        });
+
+        // The overlay follows the cursor, so it is drawn every time.
+        increment(&self.redraws.overlay);
+        let mut overlay = Frame::new(renderer, bounds.size());
+
+        // The view is only rebuilt after messages, so the counters are drawn here to stay current.
+        overlay.fill_text(canvas::Text {
+            content: format!(
+                "Redraws: background {}, data {}, overlay {}",
+                self.redraws.background.get(),
+                self.redraws.data.get(),
+                self.redraws.overlay.get(),
+            ),
+            position: Point::new(10.0, 10.0),
+            color: palette.background.base.text,
+            size: Pixels(16.0),
+            ..canvas::Text::default()
+        });
+
+        if let Some(position) = cursor.position_in(bounds) {
+            let crosshair = Path::new(|builder| {
+                builder.move_to(Point::new(position.x, 0.0));
+                builder.line_to(Point::new(position.x, bounds.height));
+                builder.move_to(Point::new(0.0, position.y));
+                builder.line_to(Point::new(bounds.width, position.y));
+            });
+            overlay.stroke(
+                &crosshair,
+                Stroke::default().with_color(palette.background.base.text),
+            );
+
+            overlay.fill_text(canvas::Text {
+                content: format!("{:.2}", 1.0 - position.y / bounds.height),
+                position: position + Vector::new(5.0, -20.0),
+                color: palette.background.base.text,
+                size: Pixels(16.0),
+                ..canvas::Text::default()
+            });
+        }
+
+        vec![background, data, overlay.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Self::State,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        if cursor.is_over(bounds) {
+            mouse::Interaction::Crosshair
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
@@ -2,45 +2,31 @@ use iced::{
     Task,
     widget::{button, column, text, text_input},
 };
+use iced_tutorial::router::{Navigation, Page, Routed, Router};
 
 fn main() -> iced::Result {
     iced::application("My App", MyApp::update, MyApp::view).run_with(MyApp::new)
 }
 
-#[derive(Debug, Clone)]
-enum Message {
-    PageA(PageAMessage),
-    PageB(PageBMessage),
-}
-
-trait Page {
-    fn update(&mut self, message: Message) -> Option<Box<dyn Page>>;
-    fn view(&self) -> iced::Element<'_, Message>;
-}
-
 struct MyApp {
-    page: Box<dyn Page>,
+    router: Router,
 }
 
 impl MyApp {
-    fn new() -> (Self, Task<Message>) {
-        (
-            Self {
-                page: Box::new(PageA::new()),
-            },
-            Task::none(),
-        )
+    fn new() -> (Self, Task<Routed>) {
+        let (router, task) = Router::new(PageA::new());
+        (Self { router }, task)
     }
 
-    fn update(&mut self, message: Message) {
-        let page = self.page.update(message);
-        if let Some(p) = page {
-            self.page = p;
-        }
+    fn update(&mut self, message: Routed) -> Task<Routed> {
+        self.router.update(message).unwrap_or_else(|stale| {
+            eprintln!("{}", stale);
+            Task::none()
+        })
     }
 
-    fn view(&self) -> iced::Element<Message> {
-        self.page.view()
+    fn view(&self) -> iced::Element<'_, Routed> {
+        self.router.view()
     }
 }
 
@@ -49,7 +35,6 @@ impl MyApp {
 enum PageBMessage {
     ButtonPressed,
 }
-type Mb = PageBMessage;
 
 struct PageB;
 
@@ -60,19 +45,18 @@ impl PageB {
 }
 
 impl Page for PageB {
-    fn update(&mut self, message: Message) -> Option<Box<dyn Page>> {
-        if let Message::PageB(msg) = message {
-            match msg {
-                PageBMessage::ButtonPressed => return Some(Box::new(PageA::new())),
-            }
+    type Message = PageBMessage;
+
+    fn update(&mut self, message: PageBMessage) -> (Navigation, Task<PageBMessage>) {
+        match message {
+            PageBMessage::ButtonPressed => (Navigation::Replace(PageA::new().into()), Task::none()),
         }
-        None
     }
 
-    fn view(&self) -> iced::Element<Message> {
+    fn view(&self) -> iced::Element<'_, PageBMessage> {
         column![
             text("Hello!"),
-            button("Log out").on_press(Message::PageB(Mb::ButtonPressed)),
+            button("Log out").on_press(PageBMessage::ButtonPressed),
         ]
         .into()
     }
@@ -84,7 +68,6 @@ enum PageAMessage {
     TextChanged(String),
     ButtonPressed,
 }
-type Ma = PageAMessage;
 
 struct PageA {
     password: String,
@@ -99,26 +82,26 @@ impl PageA {
 }
 
 impl Page for PageA {
-    fn update(&mut self, message: Message) -> Option<Box<dyn Page>> {
-        if let Message::PageA(msg) = message {
-            match msg {
-                PageAMessage::TextChanged(s) => self.password = s,
-                PageAMessage::ButtonPressed => {
-                    if self.password == "abc" {
-                        return Some(Box::new(PageB::new()));
-                    }
+    type Message = PageAMessage;
+
+    fn update(&mut self, message: PageAMessage) -> (Navigation, Task<PageAMessage>) {
+        match message {
+            PageAMessage::TextChanged(s) => self.password = s,
+            PageAMessage::ButtonPressed => {
+                if self.password == "abc" {
+                    return (Navigation::Replace(PageB::new().into()), Task::none());
                 }
             }
         }
-        None
+        (Navigation::None, Task::none())
     }
 
-    fn view(&self) -> iced::Element<Message> {
+    fn view(&self) -> iced::Element<'_, PageAMessage> {
         column![
             text_input("Password", &self.password)
                 .secure(true)
-                .on_input(|s| Message::PageA(Ma::TextChanged(s))),
-            button("Log in").on_press(Message::PageA(Ma::ButtonPressed)),
+                .on_input(PageAMessage::TextChanged),
+            button("Log in").on_press(PageAMessage::ButtonPressed),
         ]
         .into()
     }
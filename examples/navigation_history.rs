@@ -2,57 +2,31 @@ use iced::{
     Task,
     widget::{button, column, row, text},
 };
+use iced_tutorial::router::{Navigation, Page, Routed, Router};
 
 fn main() -> iced::Result {
     iced::application("My App", MyApp::update, MyApp::view).run_with(MyApp::new)
 }
 
-#[derive(Debug, Clone)]
-enum Message {
-    PageA(PageAMessage),
-    PageB(PageBMessage),
-}
-
-enum Navigation {
-    GoTo(Box<dyn Page>),
-    Back,
-    None,
-}
-
-trait Page {
-    fn update(&mut self, message: Message) -> Navigation;
-    fn view(&self) -> iced::Element<Message>;
-}
-
 struct MyApp {
-    pages: Vec<Box<dyn Page>>,
+    router: Router,
 }
 
 impl MyApp {
-    fn new() -> (Self, Task<Message>) {
-        (
-            Self {
-                pages: vec![Box::new(PageA::new())],
-            },
-            Task::none(),
-        )
+    fn new() -> (Self, Task<Routed>) {
+        let (router, task) = Router::new(PageA::new());
+        (Self { router }, task)
     }
 
-    fn update(&mut self, message: Message) {
-        let navigation = self.pages.last_mut().unwrap().update(message);
-        match navigation {
-            Navigation::GoTo(p) => self.pages.push(p),
-            Navigation::Back => {
-                if self.pages.len() > 1 {
-                    self.pages.pop();
-                }
-            }
-            Navigation::None => {}
-        }
+    fn update(&mut self, message: Routed) -> Task<Routed> {
+        self.router.update(message).unwrap_or_else(|stale| {
+            eprintln!("{}", stale);
+            Task::none()
+        })
     }
 
-    fn view(&self) -> iced::Element<Message> {
-        self.pages.last().unwrap().view()
+    fn view(&self) -> iced::Element<'_, Routed> {
+        self.router.view()
     }
 }
 
@@ -61,7 +35,6 @@ impl MyApp {
 enum PageAMessage {
     ButtonPressed,
 }
-type Ma = PageAMessage;
 
 struct PageA;
 
@@ -72,21 +45,18 @@ impl PageA {
 }
 
 impl Page for PageA {
-    fn update(&mut self, message: Message) -> Navigation {
-        if let Message::PageA(msg) = message {
-            match msg {
-                PageAMessage::ButtonPressed => {
-                    return Navigation::GoTo(Box::new(PageB::new(1)));
-                }
-            }
+    type Message = PageAMessage;
+
+    fn update(&mut self, message: PageAMessage) -> (Navigation, Task<PageAMessage>) {
+        match message {
+            PageAMessage::ButtonPressed => (Navigation::GoTo(PageB::new(1).into()), Task::none()),
         }
-        Navigation::None
     }
 
-    fn view(&self) -> iced::Element<Message> {
+    fn view(&self) -> iced::Element<'_, PageAMessage> {
         column![
             text("Start"),
-            button("Next").on_press(Message::PageA(Ma::ButtonPressed)),
+            button("Next").on_press(PageAMessage::ButtonPressed),
         ]
         .into()
     }
@@ -98,7 +68,6 @@ enum PageBMessage {
     BackButtonPressed,
     NextButtonPressed,
 }
-type Mb = PageBMessage;
 
 struct PageB {
     id: u32,
@@ -111,24 +80,22 @@ impl PageB {
 }
 
 impl Page for PageB {
-    fn update(&mut self, message: Message) -> Navigation {
-        if let Message::PageB(msg) = message {
-            match msg {
-                PageBMessage::BackButtonPressed => return Navigation::Back,
-                PageBMessage::NextButtonPressed => {
-                    return Navigation::GoTo(Box::new(PageB::new(self.id + 1)));
-                }
-            }
-        }
-        Navigation::None
+    type Message = PageBMessage;
+
+    fn update(&mut self, message: PageBMessage) -> (Navigation, Task<PageBMessage>) {
+        let navigation = match message {
+            PageBMessage::BackButtonPressed => Navigation::Back,
+            PageBMessage::NextButtonPressed => Navigation::GoTo(PageB::new(self.id + 1).into()),
+        };
+        (navigation, Task::none())
     }
 
-    fn view(&self) -> iced::Element<Message> {
+    fn view(&self) -> iced::Element<'_, PageBMessage> {
         column![
             text(self.id),
             row![
-                button("Back").on_press(Message::PageB(Mb::BackButtonPressed)),
-                button("Next").on_press(Message::PageB(Mb::NextButtonPressed)),
+                button("Back").on_press(PageBMessage::BackButtonPressed),
+                button("Next").on_press(PageBMessage::NextButtonPressed),
             ],
         ]
         .into()
@@ -0,0 +1,176 @@
+use iced::{
+    Task,
+    widget::{button, column, text, text_input},
+};
+use iced_tutorial::router::{Navigation, Page, Routed, Router};
+use std::{
+    sync::{LazyLock, Mutex},
+    time::Duration,
+};
+
+fn main() -> iced::Result {
+    iced::application("My App", MyApp::update, MyApp::view).run_with(MyApp::new)
+}
+
+struct MyApp {
+    router: Router,
+}
+
+impl MyApp {
+    fn new() -> (Self, Task<Routed>) {
+        let (router, task) = Router::new(PageA::new());
+        (Self { router }, task)
+    }
+
+    fn update(&mut self, message: Routed) -> Task<Routed> {
+        self.router.update(message).unwrap_or_else(|stale| {
+            eprintln!("{}", stale);
+            Task::none()
+        })
+    }
+
+    fn view(&self) -> iced::Element<'_, Routed> {
+        self.router.view()
+    }
+}
+
+// A pretended server
+static ITEMS: LazyLock<Mutex<Vec<String>>> =
+    LazyLock::new(|| Mutex::new(vec!["Apple".into(), "Banana".into(), "Cherry".into()]));
+
+async fn load_items() -> Vec<String> {
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    ITEMS.lock().unwrap().clone()
+}
+
+// Page A
+#[derive(Debug, Clone)]
+enum PageAMessage {
+    Loaded(Vec<String>),
+    Tick(u32),
+    ItemPressed(usize),
+}
+
+struct PageA {
+    items: Vec<String>,
+    refreshes: u32,
+    seconds: u32,
+    timer: u32,
+}
+
+impl PageA {
+    fn new() -> Self {
+        Self {
+            items: vec![],
+            refreshes: 0,
+            seconds: 0,
+            timer: 0,
+        }
+    }
+
+    fn refresh(&self) -> Task<PageAMessage> {
+        Task::perform(load_items(), PageAMessage::Loaded)
+    }
+
+    fn tick(&self) -> Task<PageAMessage> {
+        let timer = self.timer;
+        Task::perform(tokio::time::sleep(Duration::from_secs(1)), move |_| {
+            PageAMessage::Tick(timer)
+        })
+    }
+}
+
+impl Page for PageA {
+    type Message = PageAMessage;
+
+    fn update(&mut self, message: PageAMessage) -> (Navigation, Task<PageAMessage>) {
+        match message {
+            PageAMessage::Loaded(items) => {
+                self.items = items;
+                self.refreshes += 1;
+            }
+            PageAMessage::Tick(timer) => {
+                if timer == self.timer {
+                    self.seconds += 1;
+                    return (Navigation::None, self.tick());
+                }
+            }
+            PageAMessage::ItemPressed(index) => {
+                let page = PageB::new(index, self.items[index].clone());
+                return (Navigation::GoTo(page.into()), Task::none());
+            }
+        }
+        (Navigation::None, Task::none())
+    }
+
+    fn view(&self) -> iced::Element<'_, PageAMessage> {
+        column![
+            text(format!("Visible for {} seconds", self.seconds)),
+            text(format!("Refreshed {} times", self.refreshes)),
+            column(self.items.iter().enumerate().map(|(i, item)| {
+                button(text(item.as_str()))
+                    .on_press(PageAMessage::ItemPressed(i))
+                    .into()
+            })),
+        ]
+        .into()
+    }
+
+    fn on_enter(&mut self) -> Task<PageAMessage> {
+        Task::batch([self.refresh(), self.tick()])
+    }
+
+    fn on_leave(&mut self) -> Task<PageAMessage> {
+        // Ticks of the old timer are ignored from now on.
+        self.timer += 1;
+        Task::none()
+    }
+
+    fn on_resume(&mut self) -> Task<PageAMessage> {
+        Task::batch([self.refresh(), self.tick()])
+    }
+}
+
+// Page B
+#[derive(Debug, Clone)]
+enum PageBMessage {
+    TextChanged(String),
+    ButtonPressed,
+}
+
+struct PageB {
+    index: usize,
+    name: String,
+}
+
+impl PageB {
+    fn new(index: usize, name: String) -> Self {
+        Self { index, name }
+    }
+}
+
+impl Page for PageB {
+    type Message = PageBMessage;
+
+    fn update(&mut self, message: PageBMessage) -> (Navigation, Task<PageBMessage>) {
+        match message {
+            PageBMessage::TextChanged(s) => self.name = s,
+            PageBMessage::ButtonPressed => return (Navigation::Back, Task::none()),
+        }
+        (Navigation::None, Task::none())
+    }
+
+    fn view(&self) -> iced::Element<'_, PageBMessage> {
+        column![
+            text_input("Name", &self.name).on_input(PageBMessage::TextChanged),
+            button("Back").on_press(PageBMessage::ButtonPressed),
+        ]
+        .into()
+    }
+
+    fn on_destroy(&mut self) -> Task<PageBMessage> {
+        // Save the edited name before the page is dropped.
+        ITEMS.lock().unwrap()[self.index] = self.name.clone();
+        Task::none()
+    }
+}
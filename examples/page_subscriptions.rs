@@ -0,0 +1,165 @@
+use iced::{
+    Event, Subscription, Task,
+    event::{self, Status},
+    keyboard::{Key, key::Named},
+    time::{self, Duration},
+    widget::{button, column, text},
+};
+use iced_tutorial::router::{Navigation, Page, Routed, Router};
+
+fn main() -> iced::Result {
+    iced::application("My App", MyApp::update, MyApp::view)
+        .subscription(MyApp::subscription)
+        .run_with(MyApp::new)
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Back,
+    Page(Routed),
+}
+
+struct MyApp {
+    router: Router,
+}
+
+impl MyApp {
+    fn new() -> (Self, Task<Message>) {
+        let (router, task) = Router::new(PageA::new());
+        (Self { router }, task.map(Message::Page))
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        let task = match message {
+            Message::Back => self.router.navigate(Navigation::Back),
+            Message::Page(message) => self.router.update(message).unwrap_or_else(|stale| {
+                eprintln!("{}", stale);
+                Task::none()
+            }),
+        };
+        task.map(Message::Page)
+    }
+
+    fn view(&self) -> iced::Element<'_, Message> {
+        self.router.view().map(Message::Page)
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        let subscr_escape = event::listen_with(|event, status, _| match (event, status) {
+            (
+                Event::Keyboard(iced::keyboard::Event::KeyPressed {
+                    key: Key::Named(Named::Escape),
+                    ..
+                }),
+                Status::Ignored,
+            ) => Some(Message::Back),
+            _ => None,
+        });
+
+        Subscription::batch(vec![
+            subscr_escape,
+            self.router.subscription().map(Message::Page),
+        ])
+    }
+}
+
+// Page A
+#[derive(Debug, Clone)]
+enum PageAMessage {
+    StartOrStop,
+    Update,
+    ButtonPressed,
+}
+
+struct PageA {
+    seconds: u32,
+    running: bool,
+}
+
+impl PageA {
+    fn new() -> Self {
+        Self {
+            seconds: 0,
+            running: true,
+        }
+    }
+}
+
+impl Page for PageA {
+    type Message = PageAMessage;
+
+    fn update(&mut self, message: PageAMessage) -> (Navigation, Task<PageAMessage>) {
+        match message {
+            PageAMessage::StartOrStop => self.running = !self.running,
+            PageAMessage::Update => self.seconds += 1,
+            PageAMessage::ButtonPressed => {
+                return (Navigation::GoTo(PageB::new().into()), Task::none());
+            }
+        }
+        (Navigation::None, Task::none())
+    }
+
+    fn view(&self) -> iced::Element<'_, PageAMessage> {
+        column![
+            text(self.seconds),
+            text("Press Space to start or stop"),
+            button("Settings").on_press(PageAMessage::ButtonPressed),
+        ]
+        .into()
+    }
+
+    fn subscription(&self) -> Subscription<PageAMessage> {
+        let subscr_key = event::listen_with(|event, status, _| match (event, status) {
+            (
+                Event::Keyboard(iced::keyboard::Event::KeyPressed {
+                    key: Key::Named(Named::Space),
+                    ..
+                }),
+                Status::Ignored,
+            ) => Some(PageAMessage::StartOrStop),
+            _ => None,
+        });
+
+        if self.running {
+            Subscription::batch(vec![
+                subscr_key,
+                time::every(Duration::from_secs(1)).map(|_| PageAMessage::Update),
+            ])
+        } else {
+            subscr_key
+        }
+    }
+}
+
+// Page B
+#[derive(Debug, Clone)]
+enum PageBMessage {
+    ButtonPressed,
+}
+
+struct PageB;
+
+impl PageB {
+    fn new() -> Self {
+        Self
+    }
+}
+
+impl Page for PageB {
+    type Message = PageBMessage;
+
+    fn update(&mut self, message: PageBMessage) -> (Navigation, Task<PageBMessage>) {
+        match message {
+            PageBMessage::ButtonPressed => (Navigation::Back, Task::none()),
+        }
+    }
+
+    fn view(&self) -> iced::Element<'_, PageBMessage> {
+        column![
+            text("The stopwatch is paused while this page is shown"),
+            text("Press Escape to go back"),
+            button("Back").on_press(PageBMessage::ButtonPressed),
+        ]
+        .into()
+    }
+}
@@ -0,0 +1,145 @@
+use iced::{
+    Task,
+    widget::{button, column, row, text, text_input},
+};
+use iced_tutorial::router::{Navigation, Page, Routed, Router};
+use std::time::Duration;
+
+fn main() -> iced::Result {
+    iced::application("My App", MyApp::update, MyApp::view).run_with(MyApp::new)
+}
+
+struct MyApp {
+    router: Router,
+}
+
+impl MyApp {
+    fn new() -> (Self, Task<Routed>) {
+        let (router, task) = Router::new(PageA::new());
+        (Self { router }, task)
+    }
+
+    fn update(&mut self, message: Routed) -> Task<Routed> {
+        self.router.update(message).unwrap_or_else(|stale| {
+            eprintln!("{}", stale);
+            Task::none()
+        })
+    }
+
+    fn view(&self) -> iced::Element<'_, Routed> {
+        self.router.view()
+    }
+}
+
+async fn load_greeting(name: String) -> String {
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    format!("Hello {}!", name)
+}
+
+// Page A
+const NAME_INPUT_ID: &str = "name";
+
+#[derive(Debug, Clone)]
+enum PageAMessage {
+    TextChanged(String),
+    ButtonPressed,
+}
+
+struct PageA {
+    name: String,
+}
+
+impl PageA {
+    fn new() -> Self {
+        Self {
+            name: String::new(),
+        }
+    }
+}
+
+impl Page for PageA {
+    type Message = PageAMessage;
+
+    fn update(&mut self, message: PageAMessage) -> (Navigation, Task<PageAMessage>) {
+        match message {
+            PageAMessage::TextChanged(s) => self.name = s,
+            PageAMessage::ButtonPressed => {
+                let page = PageB::new(self.name.clone());
+                return (Navigation::GoTo(page.into()), Task::none());
+            }
+        }
+        (Navigation::None, Task::none())
+    }
+
+    fn view(&self) -> iced::Element<'_, PageAMessage> {
+        column![
+            text_input("Name", &self.name)
+                .id(text_input::Id::new(NAME_INPUT_ID))
+                .on_input(PageAMessage::TextChanged)
+                .on_submit(PageAMessage::ButtonPressed),
+            button("Greet").on_press(PageAMessage::ButtonPressed),
+        ]
+        .into()
+    }
+
+    fn on_enter(&mut self) -> Task<PageAMessage> {
+        text_input::focus(text_input::Id::new(NAME_INPUT_ID))
+    }
+}
+
+// Page B
+#[derive(Debug, Clone)]
+enum PageBMessage {
+    Loaded(String),
+    ReloadButtonPressed,
+    BackButtonPressed,
+}
+
+struct PageB {
+    name: String,
+    greeting: Option<String>,
+}
+
+impl PageB {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            greeting: None,
+        }
+    }
+
+    fn load(&self) -> Task<PageBMessage> {
+        Task::perform(load_greeting(self.name.clone()), PageBMessage::Loaded)
+    }
+}
+
+impl Page for PageB {
+    type Message = PageBMessage;
+
+    fn update(&mut self, message: PageBMessage) -> (Navigation, Task<PageBMessage>) {
+        match message {
+            PageBMessage::Loaded(s) => self.greeting = Some(s),
+            PageBMessage::ReloadButtonPressed => {
+                self.greeting = None;
+                return (Navigation::None, self.load());
+            }
+            PageBMessage::BackButtonPressed => return (Navigation::Back, Task::none()),
+        }
+        (Navigation::None, Task::none())
+    }
+
+    fn view(&self) -> iced::Element<'_, PageBMessage> {
+        column![
+            text(self.greeting.as_deref().unwrap_or("Loading...")),
+            row![
+                button("Reload").on_press(PageBMessage::ReloadButtonPressed),
+                button("Back").on_press(PageBMessage::BackButtonPressed),
+            ],
+        ]
+        .into()
+    }
+
+    fn on_enter(&mut self) -> Task<PageBMessage> {
+        self.load()
+    }
+}
@@ -0,0 +1,374 @@
+use iced::{
+    Color, Length, Pixels, Point, Rectangle, Renderer, Size, Theme, Vector,
+    keyboard::{self, Key, key::Named},
+    mouse,
+    widget::{
+        Canvas, button,
+        canvas::{self, Cache, Event, Frame, Geometry, Path, Program, Stroke, event},
+        column, row, text,
+    },
+};
+
+fn main() -> iced::Result {
+    iced::run("My App", MyApp::update, MyApp::view)
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    CameraChanged(Camera),
+    FitPressed,
+    ResetPressed,
+}
+
+// `None` fits the content into the canvas, whatever the size of the canvas is.
+#[derive(Default)]
+struct MyApp {
+    camera: Option<Camera>,
+    cache: Cache,
+}
+
+impl MyApp {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::CameraChanged(camera) => self.camera = Some(camera),
+            Message::FitPressed => self.camera = None,
+            Message::ResetPressed => self.camera = Some(Camera::default()),
+        }
+        self.cache.clear();
+    }
+
+    fn view(&self) -> iced::Element<'_, Message> {
+        let zoom = match self.camera {
+            Some(camera) => format!("Zoom: {:.0}%", camera.scale * 100.0),
+            None => "Zoom: fit".to_string(),
+        };
+
+        column![
+            row![
+                button("Fit").on_press(Message::FitPressed),
+                button("Reset").on_press(Message::ResetPressed),
+                text(zoom),
+                text("Scroll to zoom, and drag with the middle button or with Space held to pan"),
+            ]
+            .spacing(10)
+            .align_y(iced::Alignment::Center),
+            Canvas::new(Drawing {
+                camera: self.camera,
+                cache: &self.cache,
+            })
+            .width(Length::Fill)
+            .height(Length::Fill),
+        ]
+        .spacing(10)
+        .padding(10)
+        .into()
+    }
+}
+
+const MIN_SCALE: f32 = 0.1;
+const MAX_SCALE: f32 = 10.0;
+
+// Maps world coordinates to screen coordinates: `screen = world * scale + offset`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Camera {
+    offset: Vector,
+    scale: f32,
+}
+
+impl Default for Camera {
+    fn default() -> Self {
+        Self {
+            offset: Vector::ZERO,
+            scale: 1.0,
+        }
+    }
+}
+
+impl Camera {
+    // Scales the content to fill the viewport, keeping its aspect ratio, and centers it.
+    fn fit(content: Rectangle, viewport: Size) -> Self {
+        let scale = (viewport.width / content.width)
+            .min(viewport.height / content.height)
+            .clamp(MIN_SCALE, MAX_SCALE);
+        let center = content.center();
+
+        Self {
+            offset: Vector::new(
+                viewport.width / 2.0 - center.x * scale,
+                viewport.height / 2.0 - center.y * scale,
+            ),
+            scale,
+        }
+    }
+
+    fn to_screen(self, world: Point) -> Point {
+        Point::new(
+            world.x * self.scale + self.offset.x,
+            world.y * self.scale + self.offset.y,
+        )
+    }
+
+    fn to_world(self, screen: Point) -> Point {
+        Point::new(
+            (screen.x - self.offset.x) / self.scale,
+            (screen.y - self.offset.y) / self.scale,
+        )
+    }
+
+    fn panned(self, delta: Vector) -> Self {
+        Self {
+            offset: self.offset + delta,
+            ..self
+        }
+    }
+
+    // Zooms by `factor` while the world point under `anchor` stays under it.
+    fn zoomed(self, anchor: Point, factor: f32) -> Self {
+        let world = self.to_world(anchor);
+        let scale = (self.scale * factor).clamp(MIN_SCALE, MAX_SCALE);
+
+        Self {
+            offset: Vector::new(anchor.x - world.x * scale, anchor.y - world.y * scale),
+            scale,
+        }
+    }
+
+    // Lets `draw` work in world coordinates for the rest of `with_save`.
+    fn apply(self, frame: &mut Frame) {
+        frame.translate(self.offset);
+        frame.scale(self.scale);
+    }
+}
+
+// The part of the world that has something in it.
+const CONTENT: Rectangle = Rectangle {
+    x: -300.0,
+    y: -200.0,
+    width: 600.0,
+    height: 400.0,
+};
+
+struct Drawing<'a> {
+    camera: Option<Camera>,
+    cache: &'a Cache,
+}
+
+impl Drawing<'_> {
+    fn camera(&self, bounds: Rectangle) -> Camera {
+        self.camera
+            .unwrap_or_else(|| Camera::fit(CONTENT, bounds.size()))
+    }
+}
+
+#[derive(Default)]
+struct State {
+    is_space_pressed: bool,
+    // The last cursor position while panning.
+    panning: Option<Point>,
+}
+
+impl Program<Message> for Drawing<'_> {
+    type State = State;
+
+    fn update(
+        &self,
+        state: &mut Self::State,
+        event: Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> (event::Status, Option<Message>) {
+        let camera = self.camera(bounds);
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: Key::Named(Named::Space),
+                ..
+            }) => {
+                state.is_space_pressed = true;
+                (event::Status::Ignored, None)
+            }
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key: Key::Named(Named::Space),
+                ..
+            }) => {
+                state.is_space_pressed = false;
+                (event::Status::Ignored, None)
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(pressed))
+                if pressed == mouse::Button::Middle
+                    || (pressed == mouse::Button::Left && state.is_space_pressed) =>
+            {
+                let Some(position) = cursor.position_in(bounds) else {
+                    return (event::Status::Ignored, None);
+                };
+                state.panning = Some(position);
+                (event::Status::Captured, None)
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(
+                mouse::Button::Middle | mouse::Button::Left,
+            )) if state.panning.is_some() => {
+                state.panning = None;
+                (event::Status::Captured, None)
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let (Some(last), Some(position)) =
+                    (state.panning, cursor.position_from(bounds.position()))
+                else {
+                    return (event::Status::Ignored, None);
+                };
+                state.panning = Some(position);
+                (
+                    event::Status::Captured,
+                    Some(Message::CameraChanged(camera.panned(position - last))),
+                )
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    return (event::Status::Ignored, None);
+                };
+                // One line of a mouse wheel zooms by 10%, and touchpads scroll in pixels.
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / 50.0,
+                };
+                (
+                    event::Status::Captured,
+                    Some(Message::CameraChanged(
+                        camera.zoomed(position, 1.1_f32.powf(lines)),
+                    )),
+                )
+            }
+            _ => (event::Status::Ignored, None),
+        }
+    }
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let camera = self.camera(bounds);
+
+        let world = self.cache.draw(renderer, bounds.size(), |frame| {
+            frame.fill_rectangle(Point::ORIGIN, bounds.size(), Color::from_rgb(0.0, 0.2, 0.4));
+
+            // Everything drawn in here is in world coordinates.
+            frame.with_save(|frame| {
+                camera.apply(frame);
+
+                frame.stroke(
+                    &Path::rectangle(CONTENT.position(), CONTENT.size()),
+                    Stroke::default().with_color(Color::from_rgb(0.3, 0.5, 0.7)),
+                );
+
+                frame.fill(
+                    &Path::circle(Point::ORIGIN, 150.0),
+                    Color::from_rgb(0.6, 0.8, 1.0),
+                );
+
+                frame.stroke(
+                    &Path::line(Point::new(-250.0, 100.0), Point::new(250.0, -100.0)),
+                    Stroke {
+                        style: Color::WHITE.into(),
+                        width: 50.0,
+                        ..Default::default()
+                    },
+                );
+            });
+        });
+
+        // The label stays the same size at any zoom, so it is drawn in screen coordinates.
+        let mut overlay = Frame::new(renderer, bounds.size());
+
+        if let Some(position) = cursor.position_in(bounds) {
+            let world = camera.to_world(position);
+            overlay.fill_text(canvas::Text {
+                content: format!("({:.0}, {:.0})", world.x, world.y),
+                position: position + Vector::new(10.0, 10.0),
+                color: theme.palette().text,
+                size: Pixels(16.0),
+                ..canvas::Text::default()
+            });
+        }
+
+        let origin = camera.to_screen(Point::ORIGIN);
+        overlay.fill(&Path::circle(origin, 4.0), Color::from_rgb(1.0, 0.4, 0.4));
+
+        vec![world, overlay.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Self::State,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        if state.panning.is_some() {
+            mouse::Interaction::Grabbing
+        } else if state.is_space_pressed && cursor.is_over(bounds) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_near(a: Point, b: Point) {
+        assert!(a.distance(b) < 1e-3, "{:?} is not {:?}", a, b);
+    }
+
+    const CAMERA: Camera = Camera {
+        offset: Vector::new(30.0, -20.0),
+        scale: 2.5,
+    };
+
+    #[test]
+    fn to_world_undoes_to_screen() {
+        for point in [
+            Point::ORIGIN,
+            Point::new(12.5, -40.0),
+            Point::new(-300.0, 200.0),
+        ] {
+            assert_near(CAMERA.to_world(CAMERA.to_screen(point)), point);
+        }
+    }
+
+    #[test]
+    fn zooming_keeps_the_anchor_in_place() {
+        let anchor = Point::new(120.0, 80.0);
+        for factor in [0.5, 1.1, 3.0] {
+            let zoomed = CAMERA.zoomed(anchor, factor);
+            assert_near(zoomed.to_world(anchor), CAMERA.to_world(anchor));
+        }
+    }
+
+    #[test]
+    fn zooming_is_clamped() {
+        let anchor = Point::new(120.0, 80.0);
+
+        let zoomed = CAMERA.zoomed(anchor, 1000.0);
+        assert_eq!(zoomed.scale, MAX_SCALE);
+        assert_near(zoomed.to_world(anchor), CAMERA.to_world(anchor));
+
+        let zoomed = CAMERA.zoomed(anchor, 0.001);
+        assert_eq!(zoomed.scale, MIN_SCALE);
+        assert_near(zoomed.to_world(anchor), CAMERA.to_world(anchor));
+    }
+
+    #[test]
+    fn fit_centers_the_content() {
+        let viewport = Size::new(800.0, 600.0);
+        let camera = Camera::fit(CONTENT, viewport);
+
+        // The content is wider for its height than the viewport, so the width limits the scale.
+        assert_eq!(camera.scale, 800.0 / CONTENT.width);
+        assert_near(camera.to_screen(CONTENT.center()), Point::new(400.0, 300.0));
+    }
+}
@@ -0,0 +1,274 @@
+use iced::{
+    Subscription, Task,
+    widget::{button, column, row, text, text_input},
+    window,
+};
+use iced_tutorial::router::{AnyPage, Navigation, Page, Route, Routed, Router};
+use std::{fmt, str::FromStr};
+
+fn main() -> iced::Result {
+    iced::application("My App", MyApp::update, MyApp::view)
+        .subscription(MyApp::subscription)
+        .exit_on_close_request(false)
+        .run_with(MyApp::new)
+}
+
+const SNAPSHOT_FILE: &str = "navigation_history.txt";
+
+async fn load_snapshot() -> String {
+    tokio::fs::read_to_string(SNAPSHOT_FILE)
+        .await
+        .unwrap_or_default()
+}
+
+async fn save_snapshot(snapshot: String) {
+    if let Err(e) = tokio::fs::write(SNAPSHOT_FILE, snapshot).await {
+        eprintln!("Cannot save the navigation history: {}", e);
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Restore(String),
+    CloseRequested(window::Id),
+    Page(Routed),
+}
+
+struct MyApp {
+    router: Option<Router>,
+}
+
+impl MyApp {
+    fn new() -> (Self, Task<Message>) {
+        (
+            Self { router: None },
+            Task::perform(load_snapshot(), Message::Restore),
+        )
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Restore(snapshot) => {
+                let (router, task) = Router::restore::<PageRoute>(&snapshot)
+                    .unwrap_or_else(|| Router::new(PageA::new(String::new())));
+                self.router = Some(router);
+                task.map(Message::Page)
+            }
+            Message::CloseRequested(id) => {
+                // Without a snapshot, keep the saved one rather than overwriting it.
+                match self.router.as_ref().and_then(Router::snapshot) {
+                    Some(snapshot) => Task::future(save_snapshot(snapshot))
+                        .discard()
+                        .chain(window::close(id)),
+                    None => window::close(id),
+                }
+            }
+            Message::Page(message) => match &mut self.router {
+                Some(router) => match router.update(message) {
+                    Ok(task) => task.map(Message::Page),
+                    Err(stale) => {
+                        eprintln!("{}", stale);
+                        Task::none()
+                    }
+                },
+                None => Task::none(),
+            },
+        }
+    }
+
+    fn view(&self) -> iced::Element<'_, Message> {
+        match &self.router {
+            Some(router) => router.view().map(Message::Page),
+            None => text("Loading...").into(),
+        }
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        window::close_requests().map(Message::CloseRequested)
+    }
+}
+
+// Routes
+#[derive(Debug, Clone)]
+enum PageRoute {
+    A { name: String },
+    B { id: u32, name: String },
+}
+
+impl FromStr for PageRoute {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(name) = s.strip_prefix("/a/") {
+            return Ok(PageRoute::A { name: name.into() });
+        }
+        let b = s
+            .strip_prefix("/b/")
+            .and_then(|rest| rest.split_once('/'))
+            .and_then(|(id, name)| Some((id.parse().ok()?, name)))
+            // Page B is numbered from 1, and page A is the parent of page 1.
+            .filter(|&(id, _)| id > 0);
+        if let Some((id, name)) = b {
+            return Ok(PageRoute::B {
+                id,
+                name: name.into(),
+            });
+        }
+        Err(format!("Unknown route: {}", s))
+    }
+}
+
+impl fmt::Display for PageRoute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PageRoute::A { name } => write!(f, "/a/{}", name),
+            PageRoute::B { id, name } => write!(f, "/b/{}/{}", id, name),
+        }
+    }
+}
+
+impl Route for PageRoute {
+    fn parent(&self) -> Option<Self> {
+        match self {
+            PageRoute::A { .. } => None,
+            PageRoute::B { id: 1, name } => Some(PageRoute::A { name: name.clone() }),
+            PageRoute::B { id, name } => Some(PageRoute::B {
+                id: id - 1,
+                name: name.clone(),
+            }),
+        }
+    }
+
+    fn page(&self) -> AnyPage {
+        match self {
+            PageRoute::A { name } => PageA::new(name.clone()).into(),
+            PageRoute::B { id, name } => PageB::new(*id, name.clone()).into(),
+        }
+    }
+}
+
+// Page A
+#[derive(Debug, Clone)]
+enum PageAMessage {
+    TextChanged(String),
+    ButtonPressed,
+}
+
+struct PageA {
+    name: String,
+}
+
+impl PageA {
+    fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+impl Page for PageA {
+    type Message = PageAMessage;
+
+    fn update(&mut self, message: PageAMessage) -> (Navigation, Task<PageAMessage>) {
+        match message {
+            PageAMessage::TextChanged(s) => self.name = s,
+            PageAMessage::ButtonPressed => {
+                let page = PageB::new(1, self.name.clone());
+                return (Navigation::GoTo(page.into()), Task::none());
+            }
+        }
+        (Navigation::None, Task::none())
+    }
+
+    fn view(&self) -> iced::Element<'_, PageAMessage> {
+        column![
+            text_input("Name", &self.name).on_input(PageAMessage::TextChanged),
+            button("Next").on_press(PageAMessage::ButtonPressed),
+        ]
+        .into()
+    }
+
+    fn path(&self) -> Option<String> {
+        let route = PageRoute::A {
+            name: self.name.clone(),
+        };
+        Some(route.to_string())
+    }
+}
+
+// Page B
+#[derive(Debug, Clone)]
+enum PageBMessage {
+    BackButtonPressed,
+    NextButtonPressed,
+}
+
+struct PageB {
+    id: u32,
+    name: String,
+}
+
+impl PageB {
+    fn new(id: u32, name: String) -> Self {
+        Self { id, name }
+    }
+}
+
+impl Page for PageB {
+    type Message = PageBMessage;
+
+    fn update(&mut self, message: PageBMessage) -> (Navigation, Task<PageBMessage>) {
+        let navigation = match message {
+            PageBMessage::BackButtonPressed => Navigation::Back,
+            PageBMessage::NextButtonPressed => {
+                Navigation::GoTo(PageB::new(self.id + 1, self.name.clone()).into())
+            }
+        };
+        (navigation, Task::none())
+    }
+
+    fn view(&self) -> iced::Element<'_, PageBMessage> {
+        column![
+            text(format!("Hello {}! This is page {}.", self.name, self.id)),
+            row![
+                button("Back").on_press(PageBMessage::BackButtonPressed),
+                button("Next").on_press(PageBMessage::NextButtonPressed),
+            ],
+        ]
+        .into()
+    }
+
+    fn path(&self) -> Option<String> {
+        let route = PageRoute::B {
+            id: self.id,
+            name: self.name.clone(),
+        };
+        Some(route.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn routes_survive_a_round_trip() {
+        for path in ["/a/x", "/b/1/x", "/b/3/x"] {
+            let route: PageRoute = path.parse().unwrap();
+            assert_eq!(route.to_string(), path);
+        }
+    }
+
+    #[test]
+    fn page_b_is_numbered_from_1() {
+        assert!("/b/0/x".parse::<PageRoute>().is_err());
+    }
+
+    #[test]
+    fn corrupt_snapshots_fall_back_to_the_root_page() {
+        let mut app = MyApp { router: None };
+        let _ = app.update(Message::Restore("/a/x\n/b/0/x".into()));
+
+        let router = app.router.unwrap();
+        assert_eq!(router.depth(), 1);
+        assert_eq!(router.path().as_deref(), Some("/a/"));
+    }
+}
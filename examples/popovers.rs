@@ -0,0 +1,452 @@
+use iced::{
+    Border, Element, Event, Length, Point, Rectangle, Shadow, Size, Theme, Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        graphics::core::event,
+        layout, mouse, overlay,
+        renderer::{self, Quad},
+        widget::{Operation, Tree, tree},
+    },
+    keyboard::{self, Key, key::Named},
+    widget::{
+        checkbox, column, container, horizontal_space, pick_list, row, slider, text, vertical_space,
+    },
+};
+
+fn main() -> iced::Result {
+    iced::run("My App", MyApp::update, MyApp::view)
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    VolumeChanged(u8),
+    NotificationsToggled(bool),
+    FruitSelected(&'static str),
+}
+
+#[derive(Default)]
+struct MyApp {
+    volume: u8,
+    notifications: bool,
+    fruit: Option<&'static str>,
+}
+
+impl MyApp {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::VolumeChanged(volume) => self.volume = volume,
+            Message::NotificationsToggled(notifications) => self.notifications = notifications,
+            Message::FruitSelected(fruit) => self.fruit = Some(fruit),
+        }
+    }
+
+    fn view(&self) -> iced::Element<'_, Message> {
+        let settings = Popover::new(
+            anchor("Settings"),
+            column![
+                text(format!("Volume: {}", self.volume)),
+                slider(0..=100, self.volume, Message::VolumeChanged).width(200),
+                checkbox("Notifications", self.notifications)
+                    .on_toggle(Message::NotificationsToggled),
+                pick_list(
+                    ["Apple", "Banana", "Cherry"],
+                    self.fruit,
+                    Message::FruitSelected
+                )
+                .placeholder("Pick a fruit"),
+            ]
+            .spacing(10),
+        );
+
+        let help = Popover::new(
+            anchor("Help"),
+            text("This popover moves left\nto stay inside the window."),
+        );
+
+        let about = Popover::new(
+            anchor("About"),
+            text("This popover opens above its anchor,\nbecause there is no room below."),
+        );
+
+        column![
+            row![settings, horizontal_space(), help],
+            vertical_space(),
+            text("Click outside a popover or press Escape to close it"),
+            about,
+        ]
+        .spacing(10)
+        .padding(10)
+        .into()
+    }
+}
+
+fn anchor(label: &str) -> Element<'_, Message> {
+    container(text(label))
+        .padding([5, 10])
+        .style(container::bordered_box)
+        .into()
+}
+
+// The space between the anchor and the panel, and between the panel and its content.
+const GAP: f32 = 5.0;
+const PADDING: f32 = 10.0;
+
+#[derive(Default)]
+struct State {
+    is_open: bool,
+}
+
+struct Popover<'a, Message, Renderer> {
+    // The anchor comes first, and the content of the panel second.
+    children: [Element<'a, Message, Theme, Renderer>; 2],
+}
+
+impl<'a, Message, Renderer> Popover<'a, Message, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn new(
+        anchor: impl Into<Element<'a, Message, Theme, Renderer>>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            children: [anchor.into(), content.into()],
+        }
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Theme, Renderer> for Popover<'_, Message, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.children[0].as_widget().size()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    // Only the anchor takes space in the layout. The panel is laid out by the overlay.
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.children[0]
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.children[0].as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.children[0]
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        ) && cursor.is_over(layout.bounds())
+        {
+            state.is_open = !state.is_open;
+        }
+
+        self.children[0].as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let interaction = self.children[0].as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        if interaction == mouse::Interaction::default() && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            interaction
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        let [anchor, content] = &mut self.children;
+        let [anchor_tree, content_tree] = &mut tree.children[..] else {
+            unreachable!("a popover has two children")
+        };
+
+        if !state.is_open {
+            return anchor
+                .as_widget_mut()
+                .overlay(anchor_tree, layout, renderer, translation);
+        }
+
+        Some(overlay::Element::new(Box::new(Panel {
+            content,
+            tree: content_tree,
+            state,
+            anchor: layout.bounds() + translation,
+        })))
+    }
+}
+
+impl<'a, Message, Renderer> From<Popover<'a, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(widget: Popover<'a, Message, Renderer>) -> Self {
+        Self::new(widget)
+    }
+}
+
+// The floating panel of an open popover.
+struct Panel<'a, 'b, Message, Renderer> {
+    content: &'b mut Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    state: &'b mut State,
+    anchor: Rectangle,
+}
+
+impl<Message, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Panel<'_, '_, Message, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    // Places the panel below the anchor, or above it if there is no room below, and keeps it
+    // inside the window.
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let limits =
+            layout::Limits::new(Size::ZERO, bounds).shrink(Size::new(2.0 * PADDING, 2.0 * PADDING));
+        let content = self
+            .content
+            .as_widget()
+            .layout(self.tree, renderer, &limits)
+            .move_to(Point::new(PADDING, PADDING));
+        let size = content
+            .size()
+            .expand(Size::new(2.0 * PADDING, 2.0 * PADDING));
+
+        let below = self.anchor.y + self.anchor.height + GAP;
+        let above = self.anchor.y - GAP - size.height;
+        let y = if below + size.height <= bounds.height || above < 0.0 {
+            below
+        } else {
+            above
+        };
+        let x = self.anchor.x;
+
+        let position = Point::new(
+            x.min(bounds.width - size.width).max(0.0),
+            y.min(bounds.height - size.height).max(0.0),
+        );
+
+        layout::Node::with_children(size, vec![content]).move_to(position)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let palette = theme.extended_palette();
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    color: palette.background.strong.color,
+                    width: 1.0,
+                    radius: 5.0.into(),
+                },
+                shadow: Shadow {
+                    color: palette.background.base.text.scale_alpha(0.3),
+                    offset: Vector::new(0.0, 3.0),
+                    blur_radius: 10.0,
+                },
+            },
+            palette.background.base.color,
+        );
+
+        if let Some(content_layout) = layout.children().next() {
+            self.content.as_widget().draw(
+                self.tree,
+                renderer,
+                theme,
+                style,
+                content_layout,
+                cursor,
+                &bounds,
+            );
+        }
+    }
+
+    fn operate(&mut self, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        if let Some(content_layout) = layout.children().next() {
+            self.content
+                .as_widget()
+                .operate(self.tree, content_layout, renderer, operation);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: Key::Named(Named::Escape),
+                ..
+            }) => {
+                self.state.is_open = false;
+                return event::Status::Captured;
+            }
+            // A press on the anchor is left to the anchor, which closes the popover by itself.
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+                if !cursor.is_over(layout.bounds()) && !cursor.is_over(self.anchor) =>
+            {
+                self.state.is_open = false;
+                return event::Status::Ignored;
+            }
+            _ => {}
+        }
+
+        let Some(content_layout) = layout.children().next() else {
+            return event::Status::Ignored;
+        };
+
+        self.content.as_widget_mut().on_event(
+            self.tree,
+            event,
+            content_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &layout.bounds(),
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        layout
+            .children()
+            .next()
+            .map(|content_layout| {
+                self.content.as_widget().mouse_interaction(
+                    self.tree,
+                    content_layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .unwrap_or_default()
+    }
+
+    // The content may have overlays of its own, such as the menu of a pick list.
+    fn overlay<'c>(
+        &'c mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Theme, Renderer>> {
+        let content_layout = layout.children().next()?;
+
+        self.content
+            .as_widget_mut()
+            .overlay(self.tree, content_layout, renderer, Vector::ZERO)
+    }
+}
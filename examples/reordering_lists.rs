@@ -0,0 +1,476 @@
+use iced::{
+    Border, Color, Element, Event, Length, Point, Rectangle, Shadow, Size, Theme, Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        graphics::core::event,
+        layout, mouse, overlay,
+        renderer::{self, Quad},
+        widget::{Operation, Tree, tree},
+    },
+    keyboard::{self, Key, key::Named},
+    widget::{column, container, text},
+};
+
+fn main() -> iced::Result {
+    iced::run("My App", MyApp::update, MyApp::view)
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Reordered(usize, usize),
+}
+
+struct MyApp {
+    items: Vec<&'static str>,
+    last_move: Option<String>,
+}
+
+impl Default for MyApp {
+    fn default() -> Self {
+        Self {
+            items: vec!["Apple", "Banana", "Cherry", "Durian", "Elderberry", "Fig"],
+            last_move: None,
+        }
+    }
+}
+
+impl MyApp {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Reordered(from, to) => {
+                let item = self.items.remove(from);
+                self.items.insert(to, item);
+                self.last_move = Some(format!("Moved {} from {} to {}", item, from, to));
+            }
+        }
+    }
+
+    fn view(&self) -> iced::Element<'_, Message> {
+        let items = self.items.iter().map(|item| {
+            container(text(*item))
+                .padding(10)
+                .width(Length::Fill)
+                .style(container::rounded_box)
+                .into()
+        });
+
+        column![
+            text(
+                self.last_move
+                    .as_deref()
+                    .unwrap_or("Drag the items to reorder them, and press Escape to cancel")
+            ),
+            container(ReorderList::new(items, Message::Reordered).spacing(5.0)).width(300),
+        ]
+        .spacing(20)
+        .padding(20)
+        .into()
+    }
+}
+
+// How far the cursor has to move before a press becomes a drag.
+const DRAG_THRESHOLD: f32 = 5.0;
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    from: usize,
+    origin: Point,
+    position: Point,
+    is_dragging: bool,
+}
+
+#[derive(Default)]
+struct State {
+    drag: Option<Drag>,
+}
+
+struct ReorderList<'a, Message, Renderer> {
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    spacing: f32,
+    on_reorder: Box<dyn Fn(usize, usize) -> Message + 'a>,
+}
+
+impl<'a, Message, Renderer> ReorderList<'a, Message, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn new(
+        children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+        on_reorder: impl Fn(usize, usize) -> Message + 'a,
+    ) -> Self {
+        Self {
+            children: children.into_iter().collect(),
+            spacing: 0.0,
+            on_reorder: Box::new(on_reorder),
+        }
+    }
+
+    fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    // Returns where the dragged item would be inserted, from 0 (before the first item) to the
+    // number of items (after the last item).
+    fn slot(&self, layout: Layout<'_>, y: f32) -> usize {
+        layout
+            .children()
+            .position(|child| y < child.bounds().center_y())
+            .unwrap_or(self.children.len())
+    }
+
+    // Returns the y of the insertion marker of the given slot.
+    fn marker_y(&self, layout: Layout<'_>, slot: usize) -> f32 {
+        match slot.checked_sub(1).and_then(|i| layout.children().nth(i)) {
+            Some(above) => above.bounds().y + above.bounds().height + self.spacing / 2.0,
+            None => layout.bounds().y - self.spacing / 2.0,
+        }
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Theme, Renderer> for ReorderList<'_, Message, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fill,
+            height: Length::Shrink,
+        }
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let child_limits = limits.loose();
+
+        let mut y = 0.0;
+        let child_nodes: Vec<_> = self
+            .children
+            .iter()
+            .zip(&mut tree.children)
+            .map(|(child, tree)| {
+                let node = child
+                    .as_widget()
+                    .layout(tree, renderer, &child_limits)
+                    .move_to(Point::new(0.0, y));
+                y += node.size().height + self.spacing;
+                node
+            })
+            .collect();
+
+        let height = if child_nodes.is_empty() {
+            0.0
+        } else {
+            y - self.spacing
+        };
+        let size = limits.resolve(Length::Fill, Length::Shrink, Size::new(0.0, height));
+
+        layout::Node::with_children(size, child_nodes)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let drag = state.drag.filter(|drag| drag.is_dragging);
+        let palette = theme.extended_palette();
+
+        for (i, ((child, tree), layout)) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+        {
+            child
+                .as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, viewport);
+
+            // The dragged item fades out at its old place.
+            if drag.is_some_and(|drag| drag.from == i) {
+                renderer.fill_quad(
+                    Quad {
+                        bounds: layout.bounds(),
+                        border: Border::default(),
+                        shadow: Shadow::default(),
+                    },
+                    palette.background.base.color.scale_alpha(0.7),
+                );
+            }
+        }
+
+        let Some(drag) = drag else {
+            return;
+        };
+
+        // The insertion marker is hidden if dropping would not move the item.
+        // The ghost of the item is drawn by `overlay`.
+        let slot = self.slot(layout, drag.position.y);
+        if slot != drag.from && slot != drag.from + 1 {
+            let bounds = layout.bounds();
+            renderer.fill_quad(
+                Quad {
+                    bounds: Rectangle {
+                        x: bounds.x,
+                        y: self.marker_y(layout, slot) - 1.0,
+                        width: bounds.width,
+                        height: 2.0,
+                    },
+                    border: Border::default(),
+                    shadow: Shadow::default(),
+                },
+                palette.primary.strong.color,
+            );
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            for ((child, tree), layout) in self
+                .children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+            {
+                child.as_widget().operate(tree, layout, renderer, operation);
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            // A press only prepares a drag, so the items still receive it.
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                state.drag = cursor.position().and_then(|position| {
+                    layout
+                        .children()
+                        .position(|child| child.bounds().contains(position))
+                        .map(|from| Drag {
+                            from,
+                            origin: position,
+                            position,
+                            is_dragging: false,
+                        })
+                });
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some(drag) = &mut state.drag {
+                    drag.position = position;
+                    drag.is_dragging |= drag.origin.distance(position) > DRAG_THRESHOLD;
+
+                    // Capturing the move also makes Iced lay out the ghost again at the cursor.
+                    if drag.is_dragging {
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if let Some(drag) = state.drag.take().filter(|drag| drag.is_dragging) {
+                    let slot = self.slot(layout, drag.position.y);
+                    let to = if slot > drag.from { slot - 1 } else { slot };
+                    if to != drag.from {
+                        shell.publish((self.on_reorder)(drag.from, to));
+                    }
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: Key::Named(Named::Escape),
+                ..
+            }) if state.drag.is_some_and(|drag| drag.is_dragging) => {
+                state.drag = None;
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        if state.drag.is_some_and(|drag| drag.is_dragging) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let interaction = self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default();
+
+        if interaction == mouse::Interaction::default() && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Grab
+        } else {
+            interaction
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let drag = tree.state.downcast_ref::<State>().drag;
+        let Some(drag) = drag.filter(|drag| drag.is_dragging) else {
+            return overlay::from_children(&mut self.children, tree, layout, renderer, translation);
+        };
+
+        // While dragging, the ghost follows the cursor above everything else, even outside of the
+        // list.
+        let (Some(child), Some(tree), Some(child_layout)) = (
+            self.children.get(drag.from),
+            tree.children.get_mut(drag.from),
+            layout.children().nth(drag.from),
+        ) else {
+            return None;
+        };
+
+        Some(overlay::Element::new(Box::new(Ghost {
+            child,
+            tree,
+            bounds: child_layout.bounds() + translation + (drag.position - drag.origin),
+        })))
+    }
+}
+
+impl<'a, Message, Renderer> From<ReorderList<'a, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(widget: ReorderList<'a, Message, Renderer>) -> Self {
+        Self::new(widget)
+    }
+}
+
+// The dragged item, drawn again at the cursor.
+struct Ghost<'a, 'b, Message, Renderer> {
+    child: &'b Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    bounds: Rectangle,
+}
+
+impl<Message, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Ghost<'_, '_, Message, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, _bounds: Size) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, self.bounds.size());
+        self.child
+            .as_widget()
+            .layout(self.tree, renderer, &limits)
+            .move_to(self.bounds.position())
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+        self.child
+            .as_widget()
+            .draw(self.tree, renderer, theme, style, layout, cursor, &bounds);
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    color: theme.extended_palette().primary.strong.color,
+                    width: 2.0,
+                    radius: 5.0.into(),
+                },
+                shadow: Shadow::default(),
+            },
+            Color::TRANSPARENT,
+        );
+    }
+
+    // The ghost never takes the cursor away from the list below it.
+    fn is_over(&self, _layout: Layout<'_>, _renderer: &Renderer, _cursor_position: Point) -> bool {
+        false
+    }
+}
@@ -0,0 +1,428 @@
+use iced::{
+    Border, Element, Event, Length, Point, Rectangle, Shadow, Size, Theme, Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        graphics::core::event,
+        layout, mouse, overlay,
+        renderer::{self, Quad},
+        widget::{Operation, Tree, tree},
+    },
+    widget::{container, text},
+};
+
+fn main() -> iced::Result {
+    iced::run("My App", MyApp::update, MyApp::view)
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    SidebarResized(f32),
+    ConsoleResized(f32),
+}
+
+// The ratios are all the app needs to keep, e.g., to save them for the next start.
+struct MyApp {
+    sidebar_ratio: f32,
+    console_ratio: f32,
+}
+
+impl Default for MyApp {
+    fn default() -> Self {
+        Self {
+            sidebar_ratio: 0.25,
+            console_ratio: 0.7,
+        }
+    }
+}
+
+impl MyApp {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::SidebarResized(ratio) => self.sidebar_ratio = ratio,
+            Message::ConsoleResized(ratio) => self.console_ratio = ratio,
+        }
+    }
+
+    fn view(&self) -> iced::Element<'_, Message> {
+        let editor = Split::new(
+            Direction::Vertical,
+            self.console_ratio,
+            pane(format!("Editor: {:.0}%", self.console_ratio * 100.0)),
+            pane("Console"),
+            Message::ConsoleResized,
+        )
+        .min_size(50.0);
+
+        Split::new(
+            Direction::Horizontal,
+            self.sidebar_ratio,
+            pane(format!(
+                "Sidebar: {:.0}%\n\nBetween 100 and 300 pixels wide",
+                self.sidebar_ratio * 100.0
+            )),
+            editor,
+            Message::SidebarResized,
+        )
+        .min_size(100.0)
+        .max_size(300.0)
+        .into()
+    }
+}
+
+fn pane<'a>(content: impl text::IntoFragment<'a>) -> Element<'a, Message> {
+    container(text(content))
+        .padding(10)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+}
+
+const DIVIDER: f32 = 6.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    // The first pane is on the left, and the second pane is on the right.
+    Horizontal,
+    // The first pane is at the top, and the second pane is at the bottom.
+    Vertical,
+}
+
+#[derive(Default)]
+struct State {
+    // Where the divider was grabbed, relative to its start, while it is being dragged.
+    grab: Option<f32>,
+}
+
+struct Split<'a, Message, Renderer> {
+    direction: Direction,
+    ratio: f32,
+    min_size: f32,
+    max_size: f32,
+    children: [Element<'a, Message, Theme, Renderer>; 2],
+    on_resize: Box<dyn Fn(f32) -> Message + 'a>,
+}
+
+impl<'a, Message, Renderer> Split<'a, Message, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn new(
+        direction: Direction,
+        ratio: f32,
+        first: impl Into<Element<'a, Message, Theme, Renderer>>,
+        second: impl Into<Element<'a, Message, Theme, Renderer>>,
+        on_resize: impl Fn(f32) -> Message + 'a,
+    ) -> Self {
+        Self {
+            direction,
+            ratio,
+            min_size: 0.0,
+            max_size: f32::INFINITY,
+            children: [first.into(), second.into()],
+            on_resize: Box::new(on_resize),
+        }
+    }
+
+    // The smallest size of the first pane.
+    fn min_size(mut self, min_size: f32) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    // The largest size of the first pane.
+    fn max_size(mut self, max_size: f32) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    // Returns the space the panes share along the direction.
+    fn available(&self, size: Size) -> f32 {
+        let total = match self.direction {
+            Direction::Horizontal => size.width,
+            Direction::Vertical => size.height,
+        };
+        (total - DIVIDER).max(0.0)
+    }
+
+    // Keeps the first pane between its min and max size, and within the available space.
+    fn clamp(&self, first: f32, available: f32) -> f32 {
+        first
+            .max(self.min_size)
+            .min(self.max_size)
+            .min(available)
+            .max(0.0)
+    }
+
+    fn first_size(&self, size: Size) -> f32 {
+        let available = self.available(size);
+        self.clamp(self.ratio * available, available)
+    }
+
+    fn divider(&self, bounds: Rectangle) -> Rectangle {
+        let first = self.first_size(bounds.size());
+        match self.direction {
+            Direction::Horizontal => Rectangle {
+                x: bounds.x + first,
+                width: DIVIDER,
+                ..bounds
+            },
+            Direction::Vertical => Rectangle {
+                y: bounds.y + first,
+                height: DIVIDER,
+                ..bounds
+            },
+        }
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Theme, Renderer> for Split<'_, Message, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fill,
+            height: Length::Fill,
+        }
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = limits.resolve(Length::Fill, Length::Fill, Size::ZERO);
+        let first = self.first_size(size);
+        let second = self.available(size) - first;
+
+        let (first_size, second_size, offset) = match self.direction {
+            Direction::Horizontal => (
+                Size::new(first, size.height),
+                Size::new(second, size.height),
+                Vector::new(first + DIVIDER, 0.0),
+            ),
+            Direction::Vertical => (
+                Size::new(size.width, first),
+                Size::new(size.width, second),
+                Vector::new(0.0, first + DIVIDER),
+            ),
+        };
+
+        let [first_child, second_child] = &self.children;
+        let [first_tree, second_tree] = &mut tree.children[..] else {
+            unreachable!("a split has two children")
+        };
+
+        let first_node = first_child.as_widget().layout(
+            first_tree,
+            renderer,
+            &layout::Limits::new(Size::ZERO, first_size),
+        );
+        let second_node = second_child
+            .as_widget()
+            .layout(
+                second_tree,
+                renderer,
+                &layout::Limits::new(Size::ZERO, second_size),
+            )
+            .move_to(Point::ORIGIN + offset);
+
+        layout::Node::with_children(size, vec![first_node, second_node])
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((child, tree), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            child
+                .as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, viewport);
+        }
+
+        let state = tree.state.downcast_ref::<State>();
+        let divider = self.divider(layout.bounds());
+        let palette = theme.extended_palette();
+        let color = if state.grab.is_some() {
+            palette.primary.strong.color
+        } else if cursor.is_over(divider) {
+            palette.primary.weak.color
+        } else {
+            palette.background.strong.color
+        };
+
+        renderer.fill_quad(
+            Quad {
+                bounds: divider,
+                border: Border::default(),
+                shadow: Shadow::default(),
+            },
+            color,
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            for ((child, tree), layout) in self
+                .children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+            {
+                child.as_widget().operate(tree, layout, renderer, operation);
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let divider = self.divider(bounds);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(position) = cursor.position_over(divider) {
+                    state.grab = Some(match self.direction {
+                        Direction::Horizontal => position.x - divider.x,
+                        Direction::Vertical => position.y - divider.y,
+                    });
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some(grab) = state.grab {
+                    let first = match self.direction {
+                        Direction::Horizontal => position.x - bounds.x - grab,
+                        Direction::Vertical => position.y - bounds.y - grab,
+                    };
+                    let available = self.available(bounds.size());
+                    if available > 0.0 {
+                        let ratio = self.clamp(first, available) / available;
+                        shell.publish((self.on_resize)(ratio));
+                    }
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if state.grab.is_some() =>
+            {
+                state.grab = None;
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.grab.is_some() || cursor.is_over(self.divider(layout.bounds())) {
+            return match self.direction {
+                Direction::Horizontal => mouse::Interaction::ResizingHorizontally,
+                Direction::Vertical => mouse::Interaction::ResizingVertically,
+            };
+        }
+
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Renderer> From<Split<'a, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(widget: Split<'a, Message, Renderer>) -> Self {
+        Self::new(widget)
+    }
+}
@@ -0,0 +1,534 @@
+use iced::{
+    Alignment, Border, Color, Element, Event, Length, Padding, Rectangle, Shadow, Size, Theme,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        graphics::core::event,
+        layout, mouse,
+        renderer::{self, Quad},
+        widget::{Tree, tree},
+    },
+    keyboard::{self, key::Named},
+    widget::{button, column, row, text},
+};
+
+fn main() -> iced::Result {
+    iced::run("My App", MyApp::update, MyApp::view)
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Pressed(u32),
+    Reverse,
+}
+
+struct MyApp {
+    items: Vec<(u32, u32)>,
+}
+
+impl Default for MyApp {
+    fn default() -> Self {
+        Self {
+            items: (1..=3).map(|id| (id, 0)).collect(),
+        }
+    }
+}
+
+impl MyApp {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Pressed(id) => {
+                if let Some((_, count)) = self.items.iter_mut().find(|(i, _)| *i == id) {
+                    *count += 1;
+                }
+            }
+            Message::Reverse => self.items.reverse(),
+        }
+    }
+
+    fn view(&self) -> iced::Element<'_, Message> {
+        column![
+            button("Reverse").on_press(Message::Reverse),
+            KeyedColumn::new(self.items.iter().map(|(id, count)| {
+                (
+                    *id,
+                    row![
+                        MyWidget::new(Message::Pressed(*id)),
+                        text(format!("Widget {} pressed {} times", id, count)),
+                    ]
+                    .spacing(10)
+                    .into(),
+                )
+            }))
+            .spacing(10.0),
+        ]
+        .spacing(20)
+        .padding(20)
+        .into()
+    }
+}
+
+#[derive(Default)]
+struct State {
+    hovered: bool,
+    pressed: bool,
+    focused: bool,
+}
+
+struct MyWidget<Message> {
+    on_press: Message,
+}
+
+impl<Message> MyWidget<Message> {
+    fn new(on_press: Message) -> Self {
+        Self { on_press }
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Theme, Renderer> for MyWidget<Message>
+where
+    Message: Clone,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        _limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(Size::new(100.0, 50.0))
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        let background = if state.pressed {
+            Color::from_rgb(0.0, 0.1, 0.2)
+        } else if state.hovered {
+            Color::from_rgb(0.2, 0.4, 0.6)
+        } else {
+            Color::from_rgb(0.0, 0.2, 0.4)
+        };
+        let border = if state.focused {
+            Border {
+                color: Color::from_rgb(1.0, 0.8, 0.2),
+                width: 3.0,
+                radius: 10.0.into(),
+            }
+        } else {
+            Border {
+                color: Color::from_rgb(0.6, 0.8, 1.0),
+                width: 1.0,
+                radius: 10.0.into(),
+            }
+        };
+
+        renderer.fill_quad(
+            Quad {
+                bounds: layout.bounds(),
+                border,
+                shadow: Shadow::default(),
+            },
+            background,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let is_over = cursor.is_over(layout.bounds());
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                state.hovered = is_over;
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                state.focused = is_over;
+                if is_over {
+                    state.pressed = true;
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) if state.pressed => {
+                state.pressed = false;
+                if is_over {
+                    shell.publish(self.on_press.clone());
+                }
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(Named::Space | Named::Enter),
+                ..
+            }) if state.focused => {
+                shell.publish(self.on_press.clone());
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<MyWidget<Message>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: iced::advanced::Renderer,
+{
+    fn from(widget: MyWidget<Message>) -> Self {
+        Self::new(widget)
+    }
+}
+
+// Iced 0.13 matches the children of its `keyed_column` by their keys only when children are
+// inserted or removed. This column also moves the trees of its children along when they are
+// reordered.
+struct KeyedColumn<'a, Key, Message, Renderer> {
+    keys: Vec<Key>,
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    spacing: f32,
+}
+
+impl<'a, Key, Message, Renderer> KeyedColumn<'a, Key, Message, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Renderer: iced::advanced::Renderer,
+{
+    fn new(
+        children: impl IntoIterator<Item = (Key, Element<'a, Message, Theme, Renderer>)>,
+    ) -> Self {
+        let (keys, children) = children.into_iter().unzip();
+        Self {
+            keys,
+            children,
+            spacing: 0.0,
+        }
+    }
+
+    fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+}
+
+// The keys of the children, in the order of the trees of the children.
+struct Keys<Key>(Vec<Key>);
+
+impl<Key, Message, Renderer> Widget<Message, Theme, Renderer>
+    for KeyedColumn<'_, Key, Message, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Keys<Key>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Keys(self.keys.clone()))
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let Keys(keys) = tree.state.downcast_mut::<Keys<Key>>();
+        let mut old: Vec<_> = std::mem::take(&mut tree.children)
+            .into_iter()
+            .map(Some)
+            .collect();
+
+        // Each child takes the tree that had its key, if any, wherever that tree was.
+        tree.children = self
+            .keys
+            .iter()
+            .zip(&self.children)
+            .map(|(key, child)| {
+                let old = keys
+                    .iter()
+                    .position(|k| k == key)
+                    .and_then(|i| old[i].take());
+                match old {
+                    Some(mut tree) => {
+                        tree.diff(child);
+                        tree
+                    }
+                    None => Tree::new(child),
+                }
+            })
+            .collect();
+        keys.clone_from(&self.keys);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::flex::resolve(
+            layout::flex::Axis::Vertical,
+            renderer,
+            limits,
+            Length::Shrink,
+            Length::Shrink,
+            Padding::ZERO,
+            self.spacing,
+            Alignment::Start,
+            &self.children,
+            &mut tree.children,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((child, tree), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            child
+                .as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+impl<'a, Key, Message, Renderer> From<KeyedColumn<'a, Key, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Message: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(widget: KeyedColumn<'a, Key, Message, Renderer>) -> Self {
+        Self::new(widget)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::{Point, advanced::clipboard};
+
+    type TestElement = Element<'static, u32, Theme, ()>;
+
+    // Sends `event` with the cursor at `position`, and returns the published messages.
+    fn send(element: &mut TestElement, tree: &mut Tree, event: Event, position: Point) -> Vec<u32> {
+        let node =
+            element
+                .as_widget()
+                .layout(tree, &(), &layout::Limits::new(Size::ZERO, Size::INFINITY));
+        let mut messages = vec![];
+        let _ = element.as_widget_mut().on_event(
+            tree,
+            event,
+            Layout::new(&node),
+            mouse::Cursor::Available(position),
+            &(),
+            &mut clipboard::Null,
+            &mut Shell::new(&mut messages),
+            &Rectangle::with_size(Size::INFINITY),
+        );
+        messages
+    }
+
+    fn state(tree: &Tree) -> (bool, bool, bool) {
+        let state = tree.state.downcast_ref::<State>();
+        (state.hovered, state.pressed, state.focused)
+    }
+
+    const INSIDE: Point = Point::new(50.0, 25.0);
+    const OUTSIDE: Point = Point::new(150.0, 25.0);
+
+    #[test]
+    fn events_change_the_state() {
+        let mut element: TestElement = MyWidget::new(1).into();
+        let mut tree = Tree::new(&element);
+
+        let moved = Event::Mouse(mouse::Event::CursorMoved { position: INSIDE });
+        assert!(send(&mut element, &mut tree, moved, INSIDE).is_empty());
+        assert_eq!(state(&tree), (true, false, false));
+
+        let pressed = Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+        assert!(send(&mut element, &mut tree, pressed, INSIDE).is_empty());
+        assert_eq!(state(&tree), (true, true, true));
+
+        let released = Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left));
+        assert_eq!(send(&mut element, &mut tree, released, INSIDE), vec![1]);
+        assert_eq!(state(&tree), (true, false, true));
+    }
+
+    #[test]
+    fn releasing_outside_publishes_nothing() {
+        let mut element: TestElement = MyWidget::new(1).into();
+        let mut tree = Tree::new(&element);
+
+        let pressed = Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+        let _ = send(&mut element, &mut tree, pressed, INSIDE);
+        let moved = Event::Mouse(mouse::Event::CursorMoved { position: OUTSIDE });
+        let _ = send(&mut element, &mut tree, moved, OUTSIDE);
+        assert_eq!(state(&tree), (false, true, true));
+
+        let released = Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left));
+        assert!(send(&mut element, &mut tree, released, OUTSIDE).is_empty());
+        assert_eq!(state(&tree), (false, false, true));
+    }
+
+    #[test]
+    fn the_state_survives_a_new_widget() {
+        let mut element: TestElement = MyWidget::new(1).into();
+        let mut tree = Tree::new(&element);
+        let pressed = Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+        let _ = send(&mut element, &mut tree, pressed, INSIDE);
+
+        // `view` builds a new widget every time, but the tree keeps its state.
+        let element: TestElement = MyWidget::new(1).into();
+        tree.diff(&element);
+        assert_eq!(state(&tree), (false, true, true));
+    }
+
+    fn keyed(ids: [u32; 3]) -> TestElement {
+        KeyedColumn::new(ids.map(|id| (id, MyWidget::new(id).into()))).into()
+    }
+
+    #[test]
+    fn the_states_follow_their_keys_when_reordered() {
+        let element = keyed([1, 2, 3]);
+        let mut tree = Tree::new(&element);
+        // Only the widget with key 1 is focused.
+        tree.children[0].state.downcast_mut::<State>().focused = true;
+
+        tree.diff(keyed([3, 2, 1]));
+        let focused: Vec<_> = tree.children.iter().map(|tree| state(tree).2).collect();
+        assert_eq!(focused, [false, false, true]);
+    }
+
+    #[test]
+    fn the_states_stay_at_their_positions_in_a_column() {
+        let element: TestElement = column((1..=3).map(|id| MyWidget::new(id).into())).into();
+        let mut tree = Tree::new(&element);
+        tree.children[0].state.downcast_mut::<State>().focused = true;
+
+        let reversed: TestElement = column((1..=3).rev().map(|id| MyWidget::new(id).into())).into();
+        tree.diff(&reversed);
+        let focused: Vec<_> = tree.children.iter().map(|tree| state(tree).2).collect();
+        assert_eq!(focused, [true, false, false]);
+    }
+}
@@ -0,0 +1,157 @@
+use iced::{
+    Subscription, Task,
+    time::{self, Duration},
+    widget::{button, column, row, text},
+};
+use iced_tutorial::{
+    router::{Navigation, Page, Router},
+    tabs::{TabMessage, TabNavigator},
+};
+
+fn main() -> iced::Result {
+    iced::application("My App", MyApp::update, MyApp::view)
+        .subscription(MyApp::subscription)
+        .run_with(MyApp::new)
+}
+
+struct MyApp {
+    tabs: TabNavigator,
+}
+
+impl MyApp {
+    fn new() -> (Self, Task<TabMessage>) {
+        let mut tabs = TabNavigator::new();
+        let task = Task::batch([
+            tabs.push("Numbers", NumberPage::new("Number", 1)),
+            tabs.push("Stopwatch", StopwatchPage::new()),
+            tabs.push("Settings", SettingsPage),
+        ]);
+        (Self { tabs }, task)
+    }
+
+    fn update(&mut self, message: TabMessage) -> Task<TabMessage> {
+        self.tabs.update(message).unwrap_or_else(|stale| {
+            eprintln!("{}", stale);
+            Task::none()
+        })
+    }
+
+    fn view(&self) -> iced::Element<'_, TabMessage> {
+        self.tabs.view()
+    }
+
+    fn subscription(&self) -> Subscription<TabMessage> {
+        self.tabs.subscription()
+    }
+}
+
+// Number page
+#[derive(Debug, Clone)]
+enum NumberMessage {
+    BackButtonPressed,
+    NextButtonPressed,
+}
+
+struct NumberPage {
+    title: &'static str,
+    id: u32,
+}
+
+impl NumberPage {
+    fn new(title: &'static str, id: u32) -> Self {
+        Self { title, id }
+    }
+}
+
+impl Page for NumberPage {
+    type Message = NumberMessage;
+
+    fn update(&mut self, message: NumberMessage) -> (Navigation, Task<NumberMessage>) {
+        let navigation = match message {
+            NumberMessage::BackButtonPressed => Navigation::Back,
+            NumberMessage::NextButtonPressed => {
+                Navigation::GoTo(NumberPage::new(self.title, self.id + 1).into())
+            }
+        };
+        (navigation, Task::none())
+    }
+
+    fn view(&self) -> iced::Element<'_, NumberMessage> {
+        column![
+            text(format!("{} {}", self.title, self.id)),
+            row![
+                button("Back").on_press(NumberMessage::BackButtonPressed),
+                button("Next").on_press(NumberMessage::NextButtonPressed),
+            ],
+        ]
+        .into()
+    }
+}
+
+// Stopwatch page
+#[derive(Debug, Clone)]
+enum StopwatchMessage {
+    Update,
+}
+
+struct StopwatchPage {
+    seconds: u32,
+}
+
+impl StopwatchPage {
+    fn new() -> Self {
+        Self { seconds: 0 }
+    }
+}
+
+impl Page for StopwatchPage {
+    type Message = StopwatchMessage;
+
+    fn update(&mut self, message: StopwatchMessage) -> (Navigation, Task<StopwatchMessage>) {
+        match message {
+            StopwatchMessage::Update => self.seconds += 1,
+        }
+        (Navigation::None, Task::none())
+    }
+
+    fn view(&self) -> iced::Element<'_, StopwatchMessage> {
+        column![
+            text(self.seconds),
+            text("The stopwatch only ticks while this tab is shown"),
+        ]
+        .into()
+    }
+
+    fn subscription(&self) -> Subscription<StopwatchMessage> {
+        time::every(Duration::from_secs(1)).map(|_| StopwatchMessage::Update)
+    }
+}
+
+// Settings page
+#[derive(Debug, Clone)]
+enum SettingsMessage {
+    ButtonPressed,
+}
+
+struct SettingsPage;
+
+impl Page for SettingsPage {
+    type Message = SettingsMessage;
+
+    fn update(&mut self, message: SettingsMessage) -> (Navigation, Task<SettingsMessage>) {
+        match message {
+            SettingsMessage::ButtonPressed => {
+                let nested = Router::nested(NumberPage::new("Nested", 1));
+                (Navigation::GoTo(nested.into()), Task::none())
+            }
+        }
+    }
+
+    fn view(&self) -> iced::Element<'_, SettingsMessage> {
+        column![
+            text("Settings"),
+            button("Open nested stack").on_press(SettingsMessage::ButtonPressed),
+        ]
+        .into()
+    }
+}
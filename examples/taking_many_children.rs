@@ -0,0 +1,267 @@
+use iced::{
+    Border, Color, Element, Event, Length, Point, Rectangle, Shadow, Size, Theme, Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        graphics::core::event,
+        layout, mouse, overlay,
+        renderer::{self, Quad},
+        widget::{Operation, Tree},
+    },
+    widget::{button, container, pick_list, text, text_input},
+};
+
+fn main() -> iced::Result {
+    iced::run("My App", MyApp::update, MyApp::view)
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    ButtonPressed,
+    TextChanged(String),
+    FruitSelected(&'static str),
+}
+
+#[derive(Default)]
+struct MyApp {
+    count: u32,
+    text: String,
+    fruit: Option<&'static str>,
+}
+
+impl MyApp {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::ButtonPressed => self.count += 1,
+            Message::TextChanged(s) => self.text = s,
+            Message::FruitSelected(fruit) => self.fruit = Some(fruit),
+        }
+    }
+
+    fn view(&self) -> iced::Element<'_, Message> {
+        container(MyWidgetOuter::new([
+            text(format!("Pressed {} times", self.count)).into(),
+            button("Press me").on_press(Message::ButtonPressed).into(),
+            text_input("Type something", &self.text)
+                .on_input(Message::TextChanged)
+                .width(200)
+                .into(),
+            pick_list(
+                ["Apple", "Banana", "Cherry"],
+                self.fruit,
+                Message::FruitSelected,
+            )
+            .placeholder("Pick a fruit")
+            .into(),
+        ]))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x(Length::Fill)
+        .center_y(Length::Fill)
+        .into()
+    }
+}
+
+const PADDING: f32 = 25.0;
+const SPACING: f32 = 10.0;
+
+struct MyWidgetOuter<'a, Message, Renderer> {
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+}
+
+impl<'a, Message, Renderer> MyWidgetOuter<'a, Message, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn new(children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            children: children.into_iter().collect(),
+        }
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Theme, Renderer> for MyWidgetOuter<'_, Message, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let child_limits = limits
+            .shrink(Size::new(2.0 * PADDING, 2.0 * PADDING))
+            .loose();
+
+        let mut y = PADDING;
+        let mut width: f32 = 0.0;
+        let child_nodes: Vec<_> = self
+            .children
+            .iter()
+            .zip(&mut tree.children)
+            .map(|(child, tree)| {
+                let node = child
+                    .as_widget()
+                    .layout(tree, renderer, &child_limits)
+                    .move_to(Point::new(PADDING, y));
+                y += node.size().height + SPACING;
+                width = width.max(node.size().width);
+                node
+            })
+            .collect();
+
+        let height = if child_nodes.is_empty() {
+            y
+        } else {
+            y - SPACING
+        };
+        let size = limits.resolve(
+            Length::Shrink,
+            Length::Shrink,
+            Size::new(width + 2.0 * PADDING, height + PADDING),
+        );
+
+        layout::Node::with_children(size, child_nodes)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        renderer.fill_quad(
+            Quad {
+                bounds: layout.bounds(),
+                border: Border {
+                    color: Color::from_rgb(0.6, 0.93, 1.0),
+                    width: 1.0,
+                    radius: 10.0.into(),
+                },
+                shadow: Shadow::default(),
+            },
+            Color::from_rgb(0.0, 0.33, 0.4),
+        );
+
+        for ((child, tree), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            child
+                .as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            for ((child, tree), layout) in self
+                .children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+            {
+                child.as_widget().operate(tree, layout, renderer, operation);
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Renderer> From<MyWidgetOuter<'a, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(widget: MyWidgetOuter<'a, Message, Renderer>) -> Self {
+        Self::new(widget)
+    }
+}
@@ -0,0 +1,467 @@
+use iced::{
+    Border, Element, Event, Length, Point, Rectangle, Shadow, Size, Task, Theme, Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        graphics::core::event,
+        layout, mouse, overlay, renderer,
+        widget::{Operation, Tree},
+    },
+    time::{self, Duration, Instant},
+    widget::{button, column, container, mouse_area, row, text},
+};
+
+fn main() -> iced::Result {
+    iced::application("My App", MyApp::update, MyApp::view)
+        .subscription(MyApp::subscription)
+        .run()
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Push(Severity),
+    Save,
+    Saved,
+    Dismissed(usize),
+    Tick(Instant),
+}
+
+#[derive(Default)]
+struct MyApp {
+    toasts: Toasts,
+}
+
+impl MyApp {
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Push(severity) => {
+                let text = match severity {
+                    Severity::Info => "Something happened",
+                    Severity::Success => "Something went well",
+                    Severity::Error => "Something went wrong",
+                };
+                self.toasts.push(severity, text, Duration::from_secs(5));
+            }
+            Message::Save => {
+                self.toasts
+                    .push(Severity::Info, "Saving...", Duration::from_secs(1));
+                return Task::perform(tokio::time::sleep(Duration::from_secs(1)), |_| {
+                    Message::Saved
+                });
+            }
+            Message::Saved => self
+                .toasts
+                .push(Severity::Success, "Saved", Duration::from_secs(3)),
+            Message::Dismissed(id) => self.toasts.dismiss(id),
+            Message::Tick(now) => self.toasts.remove_expired(now),
+        }
+        Task::none()
+    }
+
+    fn view(&self) -> iced::Element<'_, Message> {
+        let content = column![
+            row![
+                button("Info").on_press(Message::Push(Severity::Info)),
+                button("Success").on_press(Message::Push(Severity::Success)),
+                button("Error").on_press(Message::Push(Severity::Error)),
+                button("Save").on_press(Message::Save),
+            ]
+            .spacing(10),
+            text("Click a toast to dismiss it"),
+        ]
+        .spacing(10)
+        .padding(10);
+
+        Manager::new(content, self.toasts.view(Message::Dismissed)).into()
+    }
+
+    // The timer only runs while there are toasts to expire.
+    fn subscription(&self) -> iced::Subscription<Message> {
+        if self.toasts.is_empty() {
+            iced::Subscription::none()
+        } else {
+            time::every(Duration::from_millis(100)).map(Message::Tick)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Severity {
+    Info,
+    Success,
+    Error,
+}
+
+impl Severity {
+    fn title(self) -> &'static str {
+        match self {
+            Severity::Info => "Info",
+            Severity::Success => "Success",
+            Severity::Error => "Error",
+        }
+    }
+
+    fn style(self, theme: &Theme) -> container::Style {
+        let palette = theme.extended_palette();
+        let pair = match self {
+            Severity::Info => palette.primary.base,
+            Severity::Success => palette.success.base,
+            Severity::Error => palette.danger.base,
+        };
+
+        container::Style {
+            text_color: Some(pair.text),
+            background: Some(pair.color.into()),
+            border: Border {
+                radius: 5.0.into(),
+                ..Border::default()
+            },
+            shadow: Shadow {
+                color: palette.background.base.text.scale_alpha(0.3),
+                offset: Vector::new(0.0, 2.0),
+                blur_radius: 8.0,
+            },
+        }
+    }
+}
+
+struct Toast {
+    id: usize,
+    severity: Severity,
+    text: String,
+    expires_at: Instant,
+}
+
+#[derive(Default)]
+struct Toasts {
+    next_id: usize,
+    toasts: Vec<Toast>,
+}
+
+impl Toasts {
+    fn push(&mut self, severity: Severity, text: impl Into<String>, timeout: Duration) {
+        self.toasts.push(Toast {
+            id: self.next_id,
+            severity,
+            text: text.into(),
+            expires_at: Instant::now() + timeout,
+        });
+        self.next_id += 1;
+    }
+
+    fn dismiss(&mut self, id: usize) {
+        self.toasts.retain(|toast| toast.id != id);
+    }
+
+    fn remove_expired(&mut self, now: Instant) {
+        self.toasts.retain(|toast| toast.expires_at > now);
+    }
+
+    fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+
+    fn view<'a, Message>(
+        &'a self,
+        on_dismiss: impl Fn(usize) -> Message,
+    ) -> impl Iterator<Item = Element<'a, Message>>
+    where
+        Message: Clone + 'a,
+    {
+        self.toasts.iter().map(move |toast| {
+            let severity = toast.severity;
+
+            mouse_area(
+                container(column![text(severity.title()).size(12), text(&toast.text)].spacing(2))
+                    .padding(10)
+                    .width(TOAST_WIDTH)
+                    .style(move |theme| severity.style(theme)),
+            )
+            .on_press(on_dismiss(toast.id))
+            .interaction(mouse::Interaction::Pointer)
+            .into()
+        })
+    }
+}
+
+const TOAST_WIDTH: f32 = 250.0;
+const SPACING: f32 = 10.0;
+
+// Shows its content, and stacks the toasts in the bottom right corner above it.
+struct Manager<'a, Message, Renderer> {
+    // The content comes first, and the toasts follow.
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Manager<'a, Message, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        toasts: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            children: std::iter::once(content.into()).chain(toasts).collect(),
+        }
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Theme, Renderer> for Manager<'_, Message, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.children[0].as_widget().size()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.children[0]
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.children[0].as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.children[0]
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.children[0].as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children[0].as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let (content, toasts) = self.children.split_at_mut(1);
+        let (content_tree, toast_trees) = tree.children.split_at_mut(1);
+
+        let content_overlay =
+            content[0]
+                .as_widget_mut()
+                .overlay(&mut content_tree[0], layout, renderer, translation);
+        let toasts_overlay = (!toasts.is_empty()).then(|| {
+            overlay::Element::new(Box::new(ToastsOverlay {
+                toasts,
+                trees: toast_trees,
+            }))
+        });
+
+        let overlays: Vec<_> = content_overlay.into_iter().chain(toasts_overlay).collect();
+        (!overlays.is_empty()).then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+impl<'a, Message, Renderer> From<Manager<'a, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(widget: Manager<'a, Message, Renderer>) -> Self {
+        Self::new(widget)
+    }
+}
+
+struct ToastsOverlay<'a, 'b, Message, Renderer> {
+    toasts: &'b mut [Element<'a, Message, Theme, Renderer>],
+    trees: &'b mut [Tree],
+}
+
+impl<Message, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for ToastsOverlay<'_, '_, Message, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    // The overlay covers the whole window, and the newest toast is at the bottom.
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, Size::new(TOAST_WIDTH, bounds.height));
+
+        let mut y = bounds.height;
+        let mut nodes: Vec<_> = self
+            .toasts
+            .iter()
+            .zip(self.trees.iter_mut())
+            .rev()
+            .map(|(toast, tree)| {
+                let node = toast.as_widget().layout(tree, renderer, &limits);
+                let size = node.size();
+                y -= SPACING + size.height;
+                node.move_to(Point::new(bounds.width - SPACING - size.width, y))
+            })
+            .collect();
+        nodes.reverse();
+
+        layout::Node::with_children(bounds, nodes)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let viewport = layout.bounds();
+
+        for ((toast, tree), layout) in self
+            .toasts
+            .iter()
+            .zip(self.trees.iter())
+            .zip(layout.children())
+        {
+            toast
+                .as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, &viewport);
+        }
+    }
+
+    fn operate(&mut self, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        for ((toast, tree), layout) in self
+            .toasts
+            .iter()
+            .zip(self.trees.iter_mut())
+            .zip(layout.children())
+        {
+            toast.as_widget().operate(tree, layout, renderer, operation);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let viewport = layout.bounds();
+
+        self.toasts
+            .iter_mut()
+            .zip(self.trees.iter_mut())
+            .zip(layout.children())
+            .map(|((toast, tree), layout)| {
+                toast.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    &viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.toasts
+            .iter()
+            .zip(self.trees.iter())
+            .zip(layout.children())
+            .map(|((toast, tree), layout)| {
+                toast
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    // Only the toasts block the content below, not the empty rest of the window.
+    fn is_over(&self, layout: Layout<'_>, _renderer: &Renderer, cursor_position: Point) -> bool {
+        layout
+            .children()
+            .any(|toast| toast.bounds().contains(cursor_position))
+    }
+}
@@ -0,0 +1,331 @@
+use std::ops::Range;
+
+use iced::{
+    Element, Length, Size, Subscription, Task,
+    widget::{
+        Space, button, checkbox, column, container, keyed_column, row, scrollable,
+        scrollable::{AbsoluteOffset, Viewport},
+        text, text_input,
+    },
+    window,
+};
+
+fn main() -> iced::Result {
+    iced::application("My App", MyApp::update, MyApp::view)
+        .subscription(MyApp::subscription)
+        .run_with(MyApp::new)
+}
+
+const LIST_ID: &str = "list";
+const ROW_COUNT: usize = 100_000;
+const ROW_HEIGHT: f32 = 30.0;
+
+// Every fifth row has two lines when the heights vary.
+fn varying_height(row: usize) -> f32 {
+    if row.is_multiple_of(5) {
+        2.0 * ROW_HEIGHT
+    } else {
+        ROW_HEIGHT
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Scrolled(Viewport),
+    WindowResized(Size),
+    VaryingHeightsToggled(bool),
+    TargetChanged(String),
+    GoPressed,
+}
+
+#[derive(Default)]
+struct MyApp {
+    viewport: Option<Viewport>,
+    window_height: Option<f32>,
+    varying_heights: bool,
+    target: String,
+}
+
+impl MyApp {
+    fn new() -> (Self, Task<Message>) {
+        let size = window::get_latest().and_then(window::get_size);
+        (Self::default(), size.map(Message::WindowResized))
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Scrolled(viewport) => self.viewport = Some(viewport),
+            Message::WindowResized(size) => self.window_height = Some(size.height),
+            Message::VaryingHeightsToggled(varying_heights) => {
+                self.varying_heights = varying_heights
+            }
+            Message::TargetChanged(target) => self.target = target,
+            Message::GoPressed => {
+                if let Ok(row) = self.target.parse::<usize>() {
+                    return scrollable::scroll_to(
+                        scrollable::Id::new(LIST_ID),
+                        AbsoluteOffset {
+                            x: 0.0,
+                            y: self.row_height().offset(row.min(ROW_COUNT - 1)),
+                        },
+                    );
+                }
+            }
+        }
+        Task::none()
+    }
+
+    fn view(&self) -> iced::Element<'_, Message> {
+        let offset = self.viewport.map(|viewport| viewport.absolute_offset().y);
+        let built = visible_rows(
+            ROW_COUNT,
+            self.row_height(),
+            offset.unwrap_or(0.0),
+            self.window_height.unwrap_or(INITIAL_HEIGHT),
+        );
+        let varying_heights = self.varying_heights;
+
+        column![
+            row![
+                text_input("Row", &self.target)
+                    .on_input(Message::TargetChanged)
+                    .on_submit(Message::GoPressed)
+                    .width(100),
+                button("Go").on_press(Message::GoPressed),
+                checkbox("Varying heights", self.varying_heights)
+                    .on_toggle(Message::VaryingHeightsToggled),
+            ]
+            .spacing(10)
+            .align_y(iced::Alignment::Center),
+            text(format!(
+                "Built rows {} to {} of {}",
+                built.start,
+                built.end.saturating_sub(1),
+                ROW_COUNT
+            )),
+            VirtualList::new(
+                ROW_COUNT,
+                self.row_height(),
+                self.viewport,
+                move |i| {
+                    if varying_heights && i.is_multiple_of(5) {
+                        text(format!("Row {}\nwith a second line", i)).into()
+                    } else {
+                        text(format!("Row {}", i)).into()
+                    }
+                },
+                Message::Scrolled,
+            )
+            .id(scrollable::Id::new(LIST_ID))
+            .max_height(self.window_height),
+        ]
+        .spacing(10)
+        .padding(10)
+        .into()
+    }
+
+    fn row_height(&self) -> RowHeight {
+        if self.varying_heights {
+            RowHeight::PerRow(varying_height)
+        } else {
+            RowHeight::Fixed(ROW_HEIGHT)
+        }
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        window::resize_events().map(|(_, size)| Message::WindowResized(size))
+    }
+}
+
+// Rows built beyond each edge of the viewport, so that a small scroll does not show a gap.
+const OVERSCAN: usize = 3;
+
+// The height the list uses until it knows how tall it can be.
+const INITIAL_HEIGHT: f32 = 1000.0;
+
+// Every row is put into a container of its height, so that the rows that are built and the spaces
+// standing in for the others always add up to the same positions.
+#[derive(Debug, Clone, Copy)]
+enum RowHeight {
+    // Every row is exactly this tall.
+    Fixed(f32),
+    // Each row is as tall as the function returns for its index.
+    PerRow(fn(usize) -> f32),
+}
+
+impl RowHeight {
+    fn height(self, row: usize) -> f32 {
+        match self {
+            RowHeight::Fixed(height) => height,
+            RowHeight::PerRow(height) => height(row),
+        }
+    }
+
+    // Returns the scroll offset of the given row, or the total height for `row_count`.
+    fn offset(self, row: usize) -> f32 {
+        match self {
+            RowHeight::Fixed(height) => row as f32 * height,
+            RowHeight::PerRow(height) => (0..row).map(height).sum(),
+        }
+    }
+}
+
+// Returns the rows that cover `height` from `offset`, together with the overscan.
+fn visible_rows(row_count: usize, row_height: RowHeight, offset: f32, height: f32) -> Range<usize> {
+    // An offset reported before the list grew may be past the end of the content.
+    let offset = offset.min(row_height.offset(row_count) - height).max(0.0);
+
+    let (first, last) = match row_height {
+        RowHeight::Fixed(row_height) => (
+            (offset / row_height).floor() as usize,
+            ((offset + height) / row_height).ceil() as usize,
+        ),
+        RowHeight::PerRow(_) => {
+            let mut first = None;
+            let mut top = 0.0;
+            let mut row = 0;
+            while row < row_count && top < offset + height {
+                top += row_height.height(row);
+                if first.is_none() && top > offset {
+                    first = Some(row);
+                }
+                row += 1;
+            }
+            (first.unwrap_or(row), row)
+        }
+    };
+
+    let last = (last + OVERSCAN).min(row_count);
+    let first = first.saturating_sub(OVERSCAN).min(last);
+    first..last
+}
+
+struct VirtualList<'a, Message> {
+    id: Option<scrollable::Id>,
+    row_count: usize,
+    row_height: RowHeight,
+    viewport: Option<Viewport>,
+    max_height: Option<f32>,
+    view_row: Box<dyn Fn(usize) -> Element<'a, Message> + 'a>,
+    on_scroll: Box<dyn Fn(Viewport) -> Message + 'a>,
+}
+
+impl<'a, Message> VirtualList<'a, Message> {
+    fn new(
+        row_count: usize,
+        row_height: RowHeight,
+        viewport: Option<Viewport>,
+        view_row: impl Fn(usize) -> Element<'a, Message> + 'a,
+        on_scroll: impl Fn(Viewport) -> Message + 'a,
+    ) -> Self {
+        Self {
+            id: None,
+            row_count,
+            row_height,
+            viewport,
+            max_height: None,
+            view_row: Box::new(view_row),
+            on_scroll: Box::new(on_scroll),
+        }
+    }
+
+    fn id(mut self, id: scrollable::Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    // Sets how tall the list can be at most, e.g., the height of the window. The rows are built
+    // for this height, so that they cover the list even before the scrollable reports its new
+    // viewport after a resize.
+    fn max_height(mut self, max_height: Option<f32>) -> Self {
+        self.max_height = max_height;
+        self
+    }
+}
+
+impl<'a, Message> From<VirtualList<'a, Message>> for Element<'a, Message>
+where
+    Message: 'a,
+{
+    fn from(list: VirtualList<'a, Message>) -> Self {
+        let offset = list.viewport.map(|viewport| viewport.absolute_offset().y);
+        let height = list
+            .max_height
+            .or(list.viewport.map(|viewport| viewport.bounds().height))
+            .unwrap_or(INITIAL_HEIGHT);
+        let rows = visible_rows(
+            list.row_count,
+            list.row_height,
+            offset.unwrap_or(0.0),
+            height,
+        );
+        let above = list.row_height.offset(rows.start);
+        let below = list.row_height.offset(list.row_count) - list.row_height.offset(rows.end);
+
+        // The rows are keyed by their indices, so that Iced can tell the rows that were added or
+        // removed at the ends of the list.
+        let built = keyed_column(rows.map(|i| {
+            let row = container((list.view_row)(i)).height(list.row_height.height(i));
+            (i, row.into())
+        }));
+
+        let content = column![
+            Space::with_height(above),
+            built.width(Length::Fill),
+            Space::with_height(below),
+        ];
+
+        let mut scrollable = scrollable(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .on_scroll(list.on_scroll);
+        if let Some(id) = list.id {
+            scrollable = scrollable.id(id);
+        }
+
+        scrollable.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VARYING: RowHeight = RowHeight::PerRow(varying_height);
+
+    #[test]
+    fn fixed_rows_cover_the_viewport() {
+        let rows = visible_rows(ROW_COUNT, RowHeight::Fixed(30.0), 300.0, 100.0);
+        assert_eq!(rows, 10 - OVERSCAN..14 + OVERSCAN);
+    }
+
+    #[test]
+    fn rows_of_varying_heights_cover_the_viewport() {
+        // Every five rows take 180 pixels, so rows 27 to 32 cover 990 to 1200.
+        assert_eq!(VARYING.offset(27), 990.0);
+        assert_eq!(VARYING.offset(33), 1200.0);
+
+        let rows = visible_rows(ROW_COUNT, VARYING, 1000.0, 200.0);
+        assert_eq!(rows, 27 - OVERSCAN..33 + OVERSCAN);
+    }
+
+    #[test]
+    fn the_rows_and_the_spaces_add_up_to_the_whole_list() {
+        let rows = visible_rows(ROW_COUNT, VARYING, 1000.0, 200.0);
+        let above = VARYING.offset(rows.start);
+        let built: f32 = rows.clone().map(|row| VARYING.height(row)).sum();
+        let below = VARYING.offset(ROW_COUNT) - VARYING.offset(rows.end);
+
+        assert_eq!(above + built + below, VARYING.offset(ROW_COUNT));
+        assert!(above <= 1000.0 && above + built >= 1200.0);
+    }
+
+    #[test]
+    fn offsets_past_the_end_are_clamped() {
+        let rows = visible_rows(10, RowHeight::Fixed(30.0), 500.0, 100.0);
+        assert_eq!(rows, 6 - OVERSCAN..10);
+
+        let rows = visible_rows(10, VARYING, 500.0, 1000.0);
+        assert_eq!(rows, 0..10);
+    }
+}
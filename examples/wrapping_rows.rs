@@ -0,0 +1,593 @@
+use iced::{
+    Alignment, Element, Event, Length, Point, Rectangle, Size, Theme, Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        graphics::core::event,
+        layout, mouse, overlay, renderer,
+        widget::{Operation, Tree},
+    },
+    widget::{button, column, container, radio, row, text},
+};
+
+fn main() -> iced::Result {
+    iced::run("My App", MyApp::update, MyApp::view)
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    JustifySelected(Justify),
+    AlignmentSelected(Align),
+    ToolPressed(usize),
+}
+
+struct MyApp {
+    justify: Justify,
+    align: Align,
+    pressed: Option<usize>,
+}
+
+impl Default for MyApp {
+    fn default() -> Self {
+        Self {
+            justify: Justify::Start,
+            align: Align::Center,
+            pressed: None,
+        }
+    }
+}
+
+impl MyApp {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::JustifySelected(justify) => self.justify = justify,
+            Message::AlignmentSelected(align) => self.align = align,
+            Message::ToolPressed(index) => self.pressed = Some(index),
+        }
+    }
+
+    fn view(&self) -> iced::Element<'_, Message> {
+        let justify = Some(self.justify);
+        let align = Some(self.align);
+
+        let tools = (0..12).map(|i| {
+            button(text(format!("Tool {}", i + 1)))
+                .padding([5 + 5 * (i as u16 % 3), 10])
+                .on_press(Message::ToolPressed(i))
+                .into()
+        });
+
+        column![
+            row![
+                radio("Start", Justify::Start, justify, Message::JustifySelected),
+                radio("Center", Justify::Center, justify, Message::JustifySelected),
+                radio(
+                    "Space between",
+                    Justify::SpaceBetween,
+                    justify,
+                    Message::JustifySelected
+                ),
+            ]
+            .spacing(10),
+            row![
+                radio("Top", Align::Top, align, Message::AlignmentSelected),
+                radio("Center", Align::Center, align, Message::AlignmentSelected),
+                radio("Bottom", Align::Bottom, align, Message::AlignmentSelected),
+            ]
+            .spacing(10),
+            container(
+                WrapRow::new(tools)
+                    .spacing(10.0)
+                    .line_spacing(5.0)
+                    .justify(self.justify)
+                    .align_items(self.align.into())
+            )
+            .padding(10)
+            .style(container::bordered_box),
+            text(match self.pressed {
+                Some(i) => format!("Tool {} pressed", i + 1),
+                None => "Resize the window to wrap the tools".into(),
+            }),
+        ]
+        .spacing(10)
+        .padding(10)
+        .into()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Align {
+    Top,
+    Center,
+    Bottom,
+}
+
+impl From<Align> for Alignment {
+    fn from(align: Align) -> Self {
+        match align {
+            Align::Top => Alignment::Start,
+            Align::Center => Alignment::Center,
+            Align::Bottom => Alignment::End,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Justify {
+    Start,
+    Center,
+    SpaceBetween,
+}
+
+struct WrapRow<'a, Message, Renderer> {
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    spacing: f32,
+    line_spacing: f32,
+    justify: Justify,
+    align_items: Alignment,
+}
+
+impl<'a, Message, Renderer> WrapRow<'a, Message, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn new(children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            children: children.into_iter().collect(),
+            spacing: 0.0,
+            line_spacing: 0.0,
+            justify: Justify::Start,
+            align_items: Alignment::Start,
+        }
+    }
+
+    fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    fn line_spacing(mut self, line_spacing: f32) -> Self {
+        self.line_spacing = line_spacing;
+        self
+    }
+
+    fn justify(mut self, justify: Justify) -> Self {
+        self.justify = justify;
+        self
+    }
+
+    fn align_items(mut self, align_items: Alignment) -> Self {
+        self.align_items = align_items;
+        self
+    }
+}
+
+// Moves the nodes into lines no wider than `max_width` and returns the size of all lines.
+fn wrap(
+    nodes: &mut [layout::Node],
+    max_width: f32,
+    spacing: f32,
+    line_spacing: f32,
+    justify: Justify,
+    align_items: Alignment,
+) -> Size {
+    // Without a bound, e.g., in a horizontal scrollable, all nodes fit into a single line that is
+    // as wide as its content, so there is no free space to justify.
+    let max_width = if max_width.is_finite() {
+        max_width
+    } else {
+        nodes.iter().map(|node| node.size().width).sum::<f32>()
+            + spacing * nodes.len().saturating_sub(1) as f32
+    };
+
+    let mut y = 0.0;
+    let mut width: f32 = 0.0;
+    let mut start = 0;
+
+    while start < nodes.len() {
+        // Take as many nodes as fit into the line, but at least one.
+        let mut end = start + 1;
+        let mut line_width = nodes[start].size().width;
+        while end < nodes.len() && line_width + spacing + nodes[end].size().width <= max_width {
+            line_width += spacing + nodes[end].size().width;
+            end += 1;
+        }
+
+        let line = &mut nodes[start..end];
+        let line_height = line
+            .iter()
+            .map(|node| node.size().height)
+            .fold(0.0, f32::max);
+        let free = (max_width - line_width).max(0.0);
+        let (mut x, gap) = match justify {
+            Justify::Start => (0.0, spacing),
+            Justify::Center => (free / 2.0, spacing),
+            Justify::SpaceBetween if line.len() > 1 => {
+                (0.0, spacing + free / (line.len() - 1) as f32)
+            }
+            Justify::SpaceBetween => (0.0, spacing),
+        };
+
+        for node in line.iter_mut() {
+            let size = node.size();
+            let offset = match align_items {
+                Alignment::Start => 0.0,
+                Alignment::Center => (line_height - size.height) / 2.0,
+                Alignment::End => line_height - size.height,
+            };
+            node.move_to_mut(Point::new(x, y + offset));
+            x += size.width + gap;
+        }
+
+        width = width.max(x - gap);
+        y += line_height + line_spacing;
+        start = end;
+    }
+
+    let height = if nodes.is_empty() {
+        0.0
+    } else {
+        y - line_spacing
+    };
+    Size::new(width, height)
+}
+
+impl<Message, Renderer> Widget<Message, Theme, Renderer> for WrapRow<'_, Message, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fill,
+            height: Length::Shrink,
+        }
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let max_width = limits.max().width;
+        let child_limits = limits.loose();
+
+        let mut nodes: Vec<_> = self
+            .children
+            .iter()
+            .zip(&mut tree.children)
+            .map(|(child, tree)| child.as_widget().layout(tree, renderer, &child_limits))
+            .collect();
+
+        let content = wrap(
+            &mut nodes,
+            max_width,
+            self.spacing,
+            self.line_spacing,
+            self.justify,
+            self.align_items,
+        );
+        // An unbounded row cannot fill its width, so it shrinks to its content.
+        let width = if max_width.is_finite() {
+            Length::Fill
+        } else {
+            Length::Shrink
+        };
+        let size = limits.resolve(width, Length::Shrink, content);
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((child, tree), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            child
+                .as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            for ((child, tree), layout) in self
+                .children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+            {
+                child.as_widget().operate(tree, layout, renderer, operation);
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Renderer> From<WrapRow<'a, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(widget: WrapRow<'a, Message, Renderer>) -> Self {
+        Self::new(widget)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::widget::Space;
+
+    fn nodes(sizes: &[(f32, f32)]) -> Vec<layout::Node> {
+        sizes
+            .iter()
+            .map(|&(width, height)| layout::Node::new(Size::new(width, height)))
+            .collect()
+    }
+
+    fn positions(nodes: &[layout::Node]) -> Vec<(f32, f32)> {
+        nodes
+            .iter()
+            .map(|node| (node.bounds().x, node.bounds().y))
+            .collect()
+    }
+
+    #[test]
+    fn wraps_at_max_width() {
+        let mut nodes = nodes(&[(40.0, 10.0); 3]);
+        let size = wrap(
+            &mut nodes,
+            100.0,
+            10.0,
+            0.0,
+            Justify::Start,
+            Alignment::Start,
+        );
+        assert_eq!(positions(&nodes), [(0.0, 0.0), (50.0, 0.0), (0.0, 10.0)]);
+        assert_eq!(size, Size::new(90.0, 20.0));
+    }
+
+    #[test]
+    fn center_shares_the_free_space_on_both_sides() {
+        let mut nodes = nodes(&[(40.0, 10.0); 2]);
+        let size = wrap(
+            &mut nodes,
+            100.0,
+            10.0,
+            0.0,
+            Justify::Center,
+            Alignment::Start,
+        );
+        assert_eq!(positions(&nodes), [(5.0, 0.0), (55.0, 0.0)]);
+        assert_eq!(size, Size::new(95.0, 10.0));
+    }
+
+    #[test]
+    fn space_between_widens_the_gaps() {
+        let mut nodes = nodes(&[(20.0, 10.0); 3]);
+        let size = wrap(
+            &mut nodes,
+            100.0,
+            10.0,
+            0.0,
+            Justify::SpaceBetween,
+            Alignment::Start,
+        );
+        assert_eq!(positions(&nodes), [(0.0, 0.0), (40.0, 0.0), (80.0, 0.0)]);
+        assert_eq!(size, Size::new(100.0, 10.0));
+    }
+
+    #[test]
+    fn space_between_keeps_a_single_child_at_the_start() {
+        let mut nodes = nodes(&[(20.0, 10.0)]);
+        let size = wrap(
+            &mut nodes,
+            100.0,
+            10.0,
+            0.0,
+            Justify::SpaceBetween,
+            Alignment::Start,
+        );
+        assert_eq!(positions(&nodes), [(0.0, 0.0)]);
+        assert_eq!(size, Size::new(20.0, 10.0));
+    }
+
+    #[test]
+    fn children_are_aligned_within_their_line() {
+        for (align_items, y) in [
+            (Alignment::Start, 0.0),
+            (Alignment::Center, 10.0),
+            (Alignment::End, 20.0),
+        ] {
+            let mut nodes = nodes(&[(10.0, 10.0), (10.0, 30.0)]);
+            let size = wrap(&mut nodes, 100.0, 0.0, 0.0, Justify::Start, align_items);
+            assert_eq!(positions(&nodes), [(0.0, y), (10.0, 0.0)]);
+            assert_eq!(size, Size::new(20.0, 30.0));
+        }
+    }
+
+    #[test]
+    fn lines_are_separated_by_line_spacing() {
+        let mut nodes = nodes(&[(60.0, 10.0); 3]);
+        let size = wrap(
+            &mut nodes,
+            100.0,
+            10.0,
+            5.0,
+            Justify::Start,
+            Alignment::Start,
+        );
+        assert_eq!(positions(&nodes), [(0.0, 0.0), (0.0, 15.0), (0.0, 30.0)]);
+        assert_eq!(size, Size::new(60.0, 40.0));
+    }
+
+    #[test]
+    fn an_unbounded_width_keeps_a_single_line() {
+        for justify in [Justify::Start, Justify::Center, Justify::SpaceBetween] {
+            let mut nodes = nodes(&[(40.0, 10.0); 3]);
+            let size = wrap(
+                &mut nodes,
+                f32::INFINITY,
+                10.0,
+                0.0,
+                justify,
+                Alignment::Start,
+            );
+            assert_eq!(positions(&nodes), [(0.0, 0.0), (50.0, 0.0), (100.0, 0.0)]);
+            assert_eq!(size, Size::new(140.0, 10.0));
+        }
+    }
+
+    // Lays out a row of three children of 40 x 10 within `limits`, and returns the size of the row
+    // and the bounds of the children.
+    fn layout_row(justify: Justify, limits: layout::Limits) -> (Size, Vec<Rectangle>) {
+        let children = (0..3).map(|_| Space::new(40.0, 10.0).into());
+        let row: Element<'_, (), Theme, ()> =
+            WrapRow::new(children).spacing(10.0).justify(justify).into();
+        let mut tree = Tree::new(&row);
+
+        let node = row.as_widget().layout(&mut tree, &(), &limits);
+        let bounds = node.children().iter().map(layout::Node::bounds).collect();
+        (node.size(), bounds)
+    }
+
+    #[test]
+    fn layout_wraps_within_the_limits() {
+        let limits = layout::Limits::new(Size::ZERO, Size::new(100.0, f32::INFINITY));
+        let (size, bounds) = layout_row(Justify::Center, limits);
+        assert_eq!(size, Size::new(100.0, 20.0));
+        assert_eq!(
+            bounds,
+            [
+                Rectangle::new(Point::new(5.0, 0.0), Size::new(40.0, 10.0)),
+                Rectangle::new(Point::new(55.0, 0.0), Size::new(40.0, 10.0)),
+                Rectangle::new(Point::new(30.0, 10.0), Size::new(40.0, 10.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn layout_shrinks_to_its_content_without_a_bound() {
+        for justify in [Justify::Start, Justify::Center, Justify::SpaceBetween] {
+            let limits = layout::Limits::new(Size::ZERO, Size::INFINITY);
+            let (size, bounds) = layout_row(justify, limits);
+            assert_eq!(size, Size::new(140.0, 10.0));
+            let x: Vec<_> = bounds.iter().map(|bounds| bounds.x).collect();
+            assert_eq!(x, [0.0, 50.0, 100.0]);
+        }
+    }
+
+    #[test]
+    fn no_children_take_no_space() {
+        let size = wrap(
+            &mut [],
+            100.0,
+            10.0,
+            5.0,
+            Justify::Center,
+            Alignment::Center,
+        );
+        assert_eq!(size, Size::ZERO);
+    }
+
+    #[test]
+    fn an_oversized_child_gets_a_line_of_its_own() {
+        let mut nodes = nodes(&[(150.0, 10.0), (20.0, 10.0)]);
+        let size = wrap(
+            &mut nodes,
+            100.0,
+            10.0,
+            0.0,
+            Justify::Center,
+            Alignment::Start,
+        );
+        assert_eq!(positions(&nodes), [(0.0, 0.0), (40.0, 10.0)]);
+        assert_eq!(size, Size::new(150.0, 20.0));
+    }
+}
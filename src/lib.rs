@@ -0,0 +1,10 @@
+//! Reusable pieces distilled from the examples of the tutorial.
+//!
+//! The examples in the `examples` directory stay self-contained wherever possible.
+//! Code that is copied into almost every app, such as the page switching of the
+//! multipage examples, lives here instead.
+
+pub mod modal;
+pub mod router;
+pub mod tabs;
+pub mod transition;
@@ -0,0 +1,38 @@
+//! A dialog shown above the rest of the app.
+
+use iced::{
+    Color, Element,
+    widget::{center, container, mouse_area, opaque, stack},
+};
+
+/// Shows `content` in the middle of `base` and blocks any interaction with `base`.
+///
+/// Clicking outside of `content` produces `on_blur`.
+pub fn modal<'a, Message>(
+    base: impl Into<Element<'a, Message>>,
+    content: impl Into<Element<'a, Message>>,
+    on_blur: Message,
+) -> Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    stack![
+        base.into(),
+        opaque(
+            mouse_area(center(opaque(content)).style(|_theme| {
+                container::Style {
+                    background: Some(
+                        Color {
+                            a: 0.8,
+                            ..Color::BLACK
+                        }
+                        .into(),
+                    ),
+                    ..container::Style::default()
+                }
+            }))
+            .on_press(on_blur)
+        )
+    ]
+    .into()
+}
@@ -0,0 +1,1058 @@
+//! Page navigation for multipage apps.
+//!
+//! This is the `Page` trait and the page stack of the Navigation History tutorial,
+//! made reusable.
+//! Every page declares its own message type. The [`Router`] tags the messages of a page
+//! with the id of the page, so that each message is delivered to the page that
+//! produced it, and only to that page.
+
+use crate::transition::{Animation, Direction, PageTransition};
+use iced::{Element, Subscription, Task, time::Instant};
+use std::{
+    any::Any,
+    error::Error,
+    fmt::{self, Debug, Display},
+    str::FromStr,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+/// A single page of a multipage app.
+pub trait Page {
+    /// The type of the messages produced and handled by the page.
+    type Message: Debug + Clone + Send + Sync + 'static;
+
+    /// Handles a message and tells the [`Router`] where to go next.
+    ///
+    /// The returned [`Task`] is run by the app, just like the one returned from the
+    /// `update` method of the app itself.
+    fn update(&mut self, message: Self::Message) -> (Navigation, Task<Self::Message>);
+
+    /// Produces the widgets of the page.
+    fn view(&self) -> Element<'_, Self::Message>;
+
+    /// Listens to events while the page is on top of the stack.
+    ///
+    /// Pages below the top page are not listened to, so their timers and key
+    /// bindings are paused until they are on top again.
+    fn subscription(&self) -> Subscription<Self::Message> {
+        Subscription::none()
+    }
+
+    /// Returns the path of the [`Route`] that leads to this page, if it has one.
+    fn path(&self) -> Option<String> {
+        None
+    }
+
+    /// Decides whether the page may be removed from the stack.
+    ///
+    /// The [`Router`] asks every page that would be popped or replaced before it
+    /// applies a [`Navigation`].
+    fn guard(&self) -> Guard {
+        Guard::Allow
+    }
+
+    /// Called when the page is put on top of the stack for the first time.
+    fn on_enter(&mut self) -> Task<Self::Message> {
+        Task::none()
+    }
+
+    /// Called when another page is pushed on top of this page.
+    ///
+    /// The page stays in the stack and still receives the messages it produced,
+    /// e.g., the result of a [`Task`], but it cannot navigate until it is on top again.
+    fn on_leave(&mut self) -> Task<Self::Message> {
+        Task::none()
+    }
+
+    /// Called when this page is revealed again because the pages above it were popped.
+    fn on_resume(&mut self) -> Task<Self::Message> {
+        Task::none()
+    }
+
+    /// Called right before the page is removed from the stack and dropped.
+    fn on_destroy(&mut self) -> Task<Self::Message> {
+        Task::none()
+    }
+}
+
+/// A page of any message type, ready to be put into a [`Router`].
+///
+/// Any [`Page`] can be turned into an [`AnyPage`] with `into`.
+/// So can a [`Router`] created by [`Router::nested`], which nests its stack of pages
+/// into another stack.
+/// When the root page of the nested stack goes back, the whole nested stack is
+/// popped from its parent.
+pub struct AnyPage(Box<dyn Erased>);
+
+impl<P: Page + 'static> From<P> for AnyPage {
+    fn from(page: P) -> Self {
+        Self(Box::new(Typed(page)))
+    }
+}
+
+impl From<Router> for AnyPage {
+    fn from(router: Router) -> Self {
+        Self(Box::new(router))
+    }
+}
+
+/// What the [`Router`] should do after a page handled a message.
+pub enum Navigation {
+    /// Pushes a new page on top of the current one.
+    GoTo(AnyPage),
+    /// Pops the current page, unless it is the root page.
+    Back,
+    /// Swaps the current page for a new one, dropping the current page.
+    Replace(AnyPage),
+    /// Pops every page except the root page.
+    PopToRoot,
+    /// Stays on the current page.
+    None,
+}
+
+/// The answer of [`Page::guard`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Guard {
+    /// The page may be removed.
+    Allow,
+    /// The page must stay, and the navigation is dropped.
+    Deny,
+    /// The user must confirm the navigation by answering the given question.
+    Confirm(String),
+}
+
+/// A typed location of a page, written as a path like `/items/42/edit`.
+///
+/// Paths are parsed with [`FromStr`] and formatted with [`Display`].
+/// A route also knows its parent, so that a deep link can be turned into a whole stack
+/// of pages.
+pub trait Route: FromStr + Display + Sized {
+    /// Returns the route of the page below this one, or `None` for the root page.
+    fn parent(&self) -> Option<Self>;
+
+    /// Creates the page of this route.
+    fn page(&self) -> AnyPage;
+}
+
+/// Identifies a page in a [`Router`].
+///
+/// Ids are never reused, not even across routers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PageId(u64);
+
+impl PageId {
+    fn unique() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl Display for PageId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+/// A message of a page, tagged with the id of the page.
+///
+/// This is the message type of a [`Router`].
+#[derive(Debug, Clone)]
+pub struct Routed {
+    page: PageId,
+    message: Payload,
+}
+
+impl Routed {
+    /// Returns the id of the page that produced the message.
+    pub fn page(&self) -> PageId {
+        self.page
+    }
+}
+
+/// The error of [`Router::update`] when the page of a message is no longer in the stack.
+///
+/// This usually happens when a [`Task`] of a page finishes after the page was popped.
+#[derive(Debug, Clone)]
+pub struct StaleMessage(pub Routed);
+
+impl Display for StaleMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Page {} is no longer in the stack, dropping {:?}",
+            self.0.page, self.0.message
+        )
+    }
+}
+
+impl Error for StaleMessage {}
+
+/// Owns the stack of pages and dispatches `update` and `view` to the pages.
+///
+/// The router also calls the lifecycle methods of [`Page`] whenever the stack changes.
+pub struct Router {
+    pages: Vec<(PageId, AnyPage)>,
+    pending: Option<(Navigation, String)>,
+    animation: Option<Animation>,
+    running: Option<Running>,
+}
+
+/// A transition that is being animated.
+struct Running {
+    /// The page that is going away, or `None` if it is the page below the top.
+    outgoing: Option<(PageId, AnyPage)>,
+    direction: Direction,
+    started: Instant,
+    now: Instant,
+}
+
+impl Router {
+    /// Creates a router whose stack only contains the given root page.
+    ///
+    /// The returned [`Task`] comes from [`Page::on_enter`] of the root page.
+    pub fn new(root: impl Into<AnyPage>) -> (Self, Task<Routed>) {
+        let mut router = Self::empty();
+        let task = router.push(root.into());
+        (router, task)
+    }
+
+    /// Creates a router that is meant to be nested into another router.
+    ///
+    /// Unlike [`Router::new`], [`Page::on_enter`] of the root page is called when the
+    /// nested router is pushed into its parent.
+    pub fn nested(root: impl Into<AnyPage>) -> Self {
+        let mut router = Self::empty();
+        router.pages.push((PageId::unique(), root.into()));
+        router
+    }
+
+    /// Creates a router whose stack holds the pages from the root page up to the given route.
+    ///
+    /// The returned [`Task`] batches the lifecycle methods of all the created pages.
+    pub fn from_route<R: Route>(route: R) -> (Self, Task<Routed>) {
+        let mut routes = vec![];
+        let mut next = Some(route);
+        while let Some(route) = next {
+            next = route.parent();
+            routes.push(route);
+        }
+        routes.reverse();
+        Self::from_routes(routes).unwrap()
+    }
+
+    /// Creates a router whose stack holds the pages of the given routes, the first one
+    /// being the root page.
+    ///
+    /// Returns `None` if there are no routes.
+    pub fn from_routes<R: Route>(
+        routes: impl IntoIterator<Item = R>,
+    ) -> Option<(Self, Task<Routed>)> {
+        let mut routes = routes.into_iter();
+        let (mut router, task) = Self::new(routes.next()?.page());
+        let mut tasks = vec![task];
+        tasks.extend(routes.map(|route| router.navigate(Navigation::GoTo(route.page()))));
+        Some((router, Task::batch(tasks)))
+    }
+
+    /// Restores a router from a snapshot made by [`Router::snapshot`].
+    ///
+    /// Returns `None` if the snapshot is empty, any of its paths cannot be parsed as `R`,
+    /// or the routes do not lead from a root route to the top one parent by parent,
+    /// so that the app can fall back to its root page.
+    pub fn restore<R: Route>(snapshot: &str) -> Option<(Self, Task<Routed>)> {
+        let routes = snapshot
+            .lines()
+            .map(|line| line.parse::<R>().ok())
+            .collect::<Option<Vec<_>>>()?;
+
+        // Routes may not implement `PartialEq`, so their paths are compared instead.
+        let mut parent = None;
+        for route in &routes {
+            if route.parent().map(|route| route.to_string()) != parent {
+                return None;
+            }
+            parent = Some(route.to_string());
+        }
+
+        Self::from_routes(routes)
+    }
+
+    /// Delivers the message to the page that produced it.
+    ///
+    /// If that page is on top, the [`Navigation`] it returns is applied as well.
+    /// The [`Task`] of the page is batched with the tasks of the lifecycle methods
+    /// so that the app can run all of them.
+    ///
+    /// Returns [`StaleMessage`] if the page is no longer in the stack.
+    pub fn update(&mut self, message: Routed) -> Result<Task<Routed>, StaleMessage> {
+        self.dispatch(message).map(|(_, task)| task)
+    }
+
+    /// Animates every change of the page on top with the given [`Animation`].
+    ///
+    /// The app must call [`Router::tick`] on every frame while [`Router::is_animating`]
+    /// returns `true`.
+    pub fn animation(mut self, animation: Animation) -> Self {
+        self.set_animation(animation);
+        self
+    }
+
+    /// Changes the [`Animation`] of the following transitions.
+    pub fn set_animation(&mut self, animation: Animation) {
+        self.animation = Some(animation);
+    }
+
+    /// Returns `true` if a transition is running, here or in a nested router.
+    pub fn is_animating(&self) -> bool {
+        self.running.is_some() || self.pages.iter().any(|(_, page)| page.0.is_animating())
+    }
+
+    /// Advances the running transitions to the given time and finishes them when they
+    /// are over, including the ones of nested routers.
+    pub fn tick(&mut self, now: Instant) {
+        for (_, page) in &mut self.pages {
+            page.0.tick(now);
+        }
+
+        let (Some(animation), Some(running)) = (&self.animation, &mut self.running) else {
+            return;
+        };
+        running.now = now;
+        if now - running.started >= animation.duration {
+            self.running = None;
+        }
+    }
+
+    /// Shows the page on top, together with the outgoing page while a transition runs.
+    pub fn view(&self) -> Element<'_, Routed> {
+        let Some(animation) = &self.animation else {
+            return view(self.top());
+        };
+
+        // The page is wrapped even when nothing is animating, so that its widget state
+        // is kept when a transition starts or ends.
+        let incoming = PageTransition::new(view(self.top()));
+        let Some(running) = &self.running else {
+            return incoming.into();
+        };
+        let outgoing = match &running.outgoing {
+            Some(page) => page,
+            None => match self.pages.iter().rev().nth(1) {
+                Some(page) => page,
+                None => return incoming.into(),
+            },
+        };
+
+        incoming
+            .outgoing(
+                view(outgoing),
+                animation.transition,
+                running.direction,
+                animation.progress(running.now - running.started),
+            )
+            .into()
+    }
+
+    /// Returns the [`Subscription`] of the page on top.
+    ///
+    /// Batch it with the subscriptions of the app itself in `subscription` of the app.
+    pub fn subscription(&self) -> Subscription<Routed> {
+        let (id, page) = self.top();
+        page.0
+            .subscription()
+            .with(*id)
+            .map(|(page, message)| Routed { page, message })
+    }
+
+    /// Applies a [`Navigation`] to the stack and returns the tasks of the lifecycle methods.
+    ///
+    /// The navigation is dropped if a page that would be removed denies it with
+    /// [`Page::guard`], or kept pending if a page asks for a confirmation.
+    pub fn navigate(&mut self, navigation: Navigation) -> Task<Routed> {
+        match self.guard_of(&navigation) {
+            Guard::Allow => self.apply(navigation),
+            Guard::Deny => Task::none(),
+            Guard::Confirm(question) => {
+                self.pending = Some((navigation, question));
+                Task::none()
+            }
+        }
+    }
+
+    /// Returns the question of the navigation that waits for a confirmation, if any.
+    ///
+    /// Show it to the user, e.g., in a [`modal`](crate::modal::modal), and call either
+    /// [`Router::confirm`] or [`Router::cancel`] with the answer.
+    /// The questions of nested routers are returned as well.
+    pub fn pending_confirmation(&self) -> Option<&str> {
+        match &self.pending {
+            Some((_, question)) => Some(question),
+            None => self
+                .pages
+                .iter()
+                .rev()
+                .find_map(|(_, page)| page.0.pending_confirmation()),
+        }
+    }
+
+    /// Applies the navigation that waits for a confirmation.
+    ///
+    /// The guards are asked again, since the stack may have changed while the user was
+    /// answering. The navigation is dropped if a page denies it now, and asks for a
+    /// confirmation again if a page has another question.
+    pub fn confirm(&mut self) -> Task<Routed> {
+        let Some((navigation, question)) = self.pending.take() else {
+            // The question may come from a nested router instead.
+            let nested = self
+                .pages
+                .iter_mut()
+                .rev()
+                .find(|(_, page)| page.0.pending_confirmation().is_some());
+            return match nested {
+                Some((id, page)) => tag(*id, page.0.confirm()),
+                None => Task::none(),
+            };
+        };
+
+        match self.guard_of(&navigation) {
+            Guard::Allow => self.apply(navigation),
+            Guard::Confirm(again) if again == question => self.apply(navigation),
+            Guard::Confirm(again) => {
+                self.pending = Some((navigation, again));
+                Task::none()
+            }
+            Guard::Deny => Task::none(),
+        }
+    }
+
+    /// Drops the navigation that waits for a confirmation, and those of nested routers.
+    pub fn cancel(&mut self) {
+        self.pending = None;
+        for (_, page) in &mut self.pages {
+            page.0.cancel();
+        }
+    }
+
+    /// Returns the path of the page on top, if it has one.
+    pub fn path(&self) -> Option<String> {
+        self.top().1.0.path()
+    }
+
+    /// Writes the paths of all the pages, from the root page to the top, one per line.
+    ///
+    /// Returns `None` if any page has no path, since the stack could not be restored.
+    /// Paths must not contain line breaks.
+    pub fn snapshot(&self) -> Option<String> {
+        let paths = self
+            .pages
+            .iter()
+            .map(|(_, page)| page.0.path())
+            .collect::<Option<Vec<_>>>()?;
+        Some(paths.join("\n"))
+    }
+
+    /// Returns the number of pages in the stack.
+    ///
+    /// The stack always holds at least the root page.
+    pub fn depth(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Returns `true` if only the root page is in the stack.
+    pub fn is_at_root(&self) -> bool {
+        self.pages.len() == 1
+    }
+
+    /// Calls [`Page::on_leave`] of the page on top, e.g., when the stack is hidden.
+    pub(crate) fn leave(&mut self) -> Task<Routed> {
+        let (id, top) = self.top_mut();
+        tag(*id, top.0.on_leave())
+    }
+
+    /// Calls [`Page::on_resume`] of the page on top, e.g., when the stack is shown again.
+    pub(crate) fn resume(&mut self) -> Task<Routed> {
+        let (id, top) = self.top_mut();
+        tag(*id, top.0.on_resume())
+    }
+
+    fn empty() -> Self {
+        Self {
+            pages: vec![],
+            pending: None,
+            animation: None,
+            running: None,
+        }
+    }
+
+    /// Delivers the message and also tells whether the root page went back, which a
+    /// nested router passes on to its parent.
+    fn dispatch(&mut self, routed: Routed) -> Result<(bool, Task<Routed>), StaleMessage> {
+        let Some(index) = self.pages.iter().position(|(id, _)| *id == routed.page) else {
+            return Err(StaleMessage(routed));
+        };
+
+        let (id, page) = &mut self.pages[index];
+        let (navigation, task) = page
+            .0
+            .update(routed.message.clone())
+            .map_err(|nested| nested.unwrap_or(StaleMessage(routed)))?;
+        let task = tag(*id, task);
+
+        // Only the page on top may navigate.
+        if index + 1 < self.pages.len() {
+            return Ok((false, task));
+        }
+        let back_from_root = matches!(navigation, Navigation::Back) && self.is_at_root();
+        Ok((
+            back_from_root,
+            Task::batch([task, self.navigate(navigation)]),
+        ))
+    }
+
+    /// Asks the guards of the pages that the navigation would remove.
+    fn guard_of(&self, navigation: &Navigation) -> Guard {
+        let removed = match navigation {
+            Navigation::Back | Navigation::PopToRoot if self.pages.len() == 1 => 0,
+            Navigation::Back | Navigation::Replace(_) => 1,
+            Navigation::PopToRoot => self.pages.len() - 1,
+            Navigation::GoTo(_) | Navigation::None => 0,
+        };
+        strictest(&self.pages[self.pages.len() - removed..])
+    }
+
+    fn apply(&mut self, navigation: Navigation) -> Task<Routed> {
+        match navigation {
+            Navigation::GoTo(page) => {
+                let (id, top) = self.top_mut();
+                let leave = tag(*id, top.0.on_leave());
+                let enter = self.push(page);
+                self.start(None, Direction::Forward);
+                Task::batch([leave, enter])
+            }
+            Navigation::Back => self.pop_to(self.pages.len() - 1),
+            Navigation::Replace(page) => {
+                let (id, top) = self.top_mut();
+                let destroy = tag(*id, top.0.on_destroy());
+                let outgoing = self.pages.pop();
+                let enter = self.push(page);
+                self.start(outgoing, Direction::Forward);
+                Task::batch([destroy, enter])
+            }
+            Navigation::PopToRoot => self.pop_to(1),
+            Navigation::None => Task::none(),
+        }
+    }
+
+    fn push(&mut self, mut page: AnyPage) -> Task<Routed> {
+        let id = PageId::unique();
+        let task = tag(id, page.0.on_enter());
+        self.pages.push((id, page));
+        task
+    }
+
+    fn pop_to(&mut self, depth: usize) -> Task<Routed> {
+        let depth = depth.max(1);
+        if self.pages.len() <= depth {
+            return Task::none();
+        }
+        let mut popped: Vec<_> = self.pages.drain(depth..).collect();
+        let mut tasks: Vec<_> = popped
+            .iter_mut()
+            .rev()
+            .map(|(id, page)| tag(*id, page.0.on_destroy()))
+            .collect();
+        let (id, top) = self.top_mut();
+        tasks.push(tag(*id, top.0.on_resume()));
+        self.start(popped.pop(), Direction::Backward);
+        Task::batch(tasks)
+    }
+
+    /// Starts a transition if the router is animated, cancelling the running one.
+    fn start(&mut self, outgoing: Option<(PageId, AnyPage)>, direction: Direction) {
+        if self.animation.is_some() {
+            let now = Instant::now();
+            self.running = Some(Running {
+                outgoing,
+                direction,
+                started: now,
+                now,
+            });
+        }
+    }
+
+    fn top(&self) -> &(PageId, AnyPage) {
+        self.pages.last().unwrap()
+    }
+
+    fn top_mut(&mut self) -> &mut (PageId, AnyPage) {
+        self.pages.last_mut().unwrap()
+    }
+}
+
+/// Combines the guards of the given pages.
+///
+/// A single [`Guard::Deny`] denies the navigation, even if a page above it asks for a
+/// confirmation. Otherwise, the question of the topmost page that asks one is used.
+fn strictest(pages: &[(PageId, AnyPage)]) -> Guard {
+    let mut strictest = Guard::Allow;
+    for (_, page) in pages.iter().rev() {
+        match page.0.guard() {
+            Guard::Allow => {}
+            Guard::Deny => return Guard::Deny,
+            confirm @ Guard::Confirm(_) => {
+                if strictest == Guard::Allow {
+                    strictest = confirm;
+                }
+            }
+        }
+    }
+    strictest
+}
+
+fn view((id, page): &(PageId, AnyPage)) -> Element<'_, Routed> {
+    let id = *id;
+    page.0
+        .view()
+        .map(move |message| Routed { page: id, message })
+}
+
+fn tag(id: PageId, task: Task<Payload>) -> Task<Routed> {
+    task.map(move |message| Routed { page: id, message })
+}
+
+/// A message of any page.
+type Payload = Arc<dyn AnyMessage>;
+
+trait AnyMessage: Any + Debug + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T: Any + Debug + Send + Sync> AnyMessage for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+fn erase<T: AnyMessage>(message: T) -> Payload {
+    Arc::new(message)
+}
+
+fn downcast<T: Clone + 'static>(payload: &Payload) -> Option<T> {
+    // `Payload` is an `AnyMessage` itself, so look through the `Arc` first.
+    let message: &dyn AnyMessage = payload.as_ref();
+    message.as_any().downcast_ref::<T>().cloned()
+}
+
+/// A [`Page`] whose message type is hidden behind [`Payload`].
+trait Erased {
+    /// Fails with `None` if the message is of another type, or with the
+    /// [`StaleMessage`] of a nested router.
+    fn update(
+        &mut self,
+        message: Payload,
+    ) -> Result<(Navigation, Task<Payload>), Option<StaleMessage>>;
+    fn view(&self) -> Element<'_, Payload>;
+    fn subscription(&self) -> Subscription<Payload>;
+    fn path(&self) -> Option<String>;
+    fn guard(&self) -> Guard;
+    fn on_enter(&mut self) -> Task<Payload>;
+    fn on_leave(&mut self) -> Task<Payload>;
+    fn on_resume(&mut self) -> Task<Payload>;
+    fn on_destroy(&mut self) -> Task<Payload>;
+
+    // Only a nested router waits for confirmations and runs transitions of its own.
+    fn pending_confirmation(&self) -> Option<&str> {
+        None
+    }
+
+    fn confirm(&mut self) -> Task<Payload> {
+        Task::none()
+    }
+
+    fn cancel(&mut self) {}
+
+    fn is_animating(&self) -> bool {
+        false
+    }
+
+    fn tick(&mut self, _now: Instant) {}
+}
+
+struct Typed<P>(P);
+
+impl<P: Page> Erased for Typed<P> {
+    fn update(
+        &mut self,
+        message: Payload,
+    ) -> Result<(Navigation, Task<Payload>), Option<StaleMessage>> {
+        let message = downcast::<P::Message>(&message).ok_or(None)?;
+        let (navigation, task) = self.0.update(message);
+        Ok((navigation, task.map(erase)))
+    }
+
+    fn view(&self) -> Element<'_, Payload> {
+        self.0.view().map(erase)
+    }
+
+    fn subscription(&self) -> Subscription<Payload> {
+        self.0.subscription().map(erase)
+    }
+
+    fn path(&self) -> Option<String> {
+        self.0.path()
+    }
+
+    fn guard(&self) -> Guard {
+        self.0.guard()
+    }
+
+    fn on_enter(&mut self) -> Task<Payload> {
+        self.0.on_enter().map(erase)
+    }
+
+    fn on_leave(&mut self) -> Task<Payload> {
+        self.0.on_leave().map(erase)
+    }
+
+    fn on_resume(&mut self) -> Task<Payload> {
+        self.0.on_resume().map(erase)
+    }
+
+    fn on_destroy(&mut self) -> Task<Payload> {
+        self.0.on_destroy().map(erase)
+    }
+}
+
+impl Erased for Router {
+    fn update(
+        &mut self,
+        message: Payload,
+    ) -> Result<(Navigation, Task<Payload>), Option<StaleMessage>> {
+        let routed = downcast::<Routed>(&message).ok_or(None)?;
+        let (back_from_root, task) = self.dispatch(routed).map_err(Some)?;
+        let navigation = if back_from_root {
+            Navigation::Back
+        } else {
+            Navigation::None
+        };
+        Ok((navigation, task.map(erase)))
+    }
+
+    fn view(&self) -> Element<'_, Payload> {
+        Router::view(self).map(erase)
+    }
+
+    fn subscription(&self) -> Subscription<Payload> {
+        Router::subscription(self).map(erase)
+    }
+
+    fn path(&self) -> Option<String> {
+        Router::path(self)
+    }
+
+    fn guard(&self) -> Guard {
+        strictest(&self.pages)
+    }
+
+    fn on_enter(&mut self) -> Task<Payload> {
+        let (id, top) = self.top_mut();
+        tag(*id, top.0.on_enter()).map(erase)
+    }
+
+    fn on_leave(&mut self) -> Task<Payload> {
+        self.leave().map(erase)
+    }
+
+    fn on_resume(&mut self) -> Task<Payload> {
+        self.resume().map(erase)
+    }
+
+    fn on_destroy(&mut self) -> Task<Payload> {
+        let tasks: Vec<_> = self
+            .pages
+            .iter_mut()
+            .rev()
+            .map(|(id, page)| tag(*id, page.0.on_destroy()))
+            .collect();
+        Task::batch(tasks).map(erase)
+    }
+
+    fn pending_confirmation(&self) -> Option<&str> {
+        Router::pending_confirmation(self)
+    }
+
+    fn confirm(&mut self) -> Task<Payload> {
+        Router::confirm(self).map(erase)
+    }
+
+    fn cancel(&mut self) {
+        Router::cancel(self);
+    }
+
+    fn is_animating(&self) -> bool {
+        Router::is_animating(self)
+    }
+
+    fn tick(&mut self, now: Instant) {
+        Router::tick(self, now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transition::Transition;
+    use iced::widget::text;
+    use std::sync::atomic::AtomicUsize;
+
+    // A page that counts the messages it receives.
+    struct Counter {
+        path: &'static str,
+        count: Arc<AtomicUsize>,
+    }
+
+    impl Counter {
+        fn new(path: &'static str) -> (Self, Arc<AtomicUsize>) {
+            let count = Arc::new(AtomicUsize::new(0));
+            let page = Self {
+                path,
+                count: count.clone(),
+            };
+            (page, count)
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct Increment;
+
+    impl Page for Counter {
+        type Message = Increment;
+
+        fn update(&mut self, _message: Increment) -> (Navigation, Task<Increment>) {
+            self.count.fetch_add(1, Ordering::Relaxed);
+            (Navigation::None, Task::none())
+        }
+
+        fn view(&self) -> Element<'_, Increment> {
+            text(self.path).into()
+        }
+
+        fn path(&self) -> Option<String> {
+            Some(self.path.to_string())
+        }
+    }
+
+    // `/` is the root of `/a`, which is the parent of `/a/b`.
+    #[derive(Debug, Clone, Copy)]
+    enum TestRoute {
+        Root,
+        A,
+        B,
+    }
+
+    impl FromStr for TestRoute {
+        type Err = ();
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s {
+                "/" => Ok(TestRoute::Root),
+                "/a" => Ok(TestRoute::A),
+                "/a/b" => Ok(TestRoute::B),
+                _ => Err(()),
+            }
+        }
+    }
+
+    impl Display for TestRoute {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.path())
+        }
+    }
+
+    impl TestRoute {
+        fn path(self) -> &'static str {
+            match self {
+                TestRoute::Root => "/",
+                TestRoute::A => "/a",
+                TestRoute::B => "/a/b",
+            }
+        }
+    }
+
+    impl Route for TestRoute {
+        fn parent(&self) -> Option<Self> {
+            match self {
+                TestRoute::Root => None,
+                TestRoute::A => Some(TestRoute::Root),
+                TestRoute::B => Some(TestRoute::A),
+            }
+        }
+
+        fn page(&self) -> AnyPage {
+            Counter::new(self.path()).0.into()
+        }
+    }
+
+    // A page whose guard can be changed while it is in the stack.
+    struct Guarded(Arc<std::sync::Mutex<Guard>>);
+
+    impl Guarded {
+        fn new(guard: Guard) -> (Self, Arc<std::sync::Mutex<Guard>>) {
+            let guard = Arc::new(std::sync::Mutex::new(guard));
+            (Self(guard.clone()), guard)
+        }
+    }
+
+    impl Page for Guarded {
+        type Message = Increment;
+
+        fn update(&mut self, _message: Increment) -> (Navigation, Task<Increment>) {
+            (Navigation::None, Task::none())
+        }
+
+        fn view(&self) -> Element<'_, Increment> {
+            text("Guarded").into()
+        }
+
+        fn guard(&self) -> Guard {
+            self.0.lock().unwrap().clone()
+        }
+    }
+
+    #[test]
+    fn messages_of_popped_pages_are_stale() {
+        let (root, root_count) = Counter::new("/");
+        let (mut router, _) = Router::new(root);
+        let (page, count) = Counter::new("/a");
+        let _ = router.navigate(Navigation::GoTo(page.into()));
+
+        // The message is produced while the page is on top, e.g., by a running task.
+        let popped = router.pages[1].0;
+        let message = Routed {
+            page: popped,
+            message: erase(Increment),
+        };
+        let _ = router.navigate(Navigation::Back);
+
+        let Err(stale) = router.update(message) else {
+            panic!("the message of a popped page was delivered");
+        };
+        assert_eq!(stale.0.page(), popped);
+        assert_eq!(count.load(Ordering::Relaxed), 0);
+        assert_eq!(root_count.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn messages_reach_pages_below_the_top() {
+        let (root, root_count) = Counter::new("/");
+        let (mut router, _) = Router::new(root);
+        let (page, top_count) = Counter::new("/a");
+        let _ = router.navigate(Navigation::GoTo(page.into()));
+
+        let message = Routed {
+            page: router.pages[0].0,
+            message: erase(Increment),
+        };
+        assert!(router.update(message).is_ok());
+        assert_eq!(root_count.load(Ordering::Relaxed), 1);
+        assert_eq!(top_count.load(Ordering::Relaxed), 0);
+        assert_eq!(router.depth(), 2);
+    }
+
+    #[test]
+    fn deny_wins_over_a_confirmation_above_it() {
+        let (mut router, _) = Router::new(Counter::new("/").0);
+        let _ = router.navigate(Navigation::GoTo(Guarded::new(Guard::Deny).0.into()));
+        let _ = router.navigate(Navigation::GoTo(
+            Guarded::new(Guard::Confirm("Discard?".into())).0.into(),
+        ));
+
+        let _ = router.navigate(Navigation::PopToRoot);
+        assert_eq!(router.pending_confirmation(), None);
+        assert_eq!(router.depth(), 3);
+
+        // Going back only removes the page that asks.
+        let _ = router.navigate(Navigation::Back);
+        assert_eq!(router.pending_confirmation(), Some("Discard?"));
+        let _ = router.confirm();
+        assert_eq!(router.depth(), 2);
+    }
+
+    #[test]
+    fn confirm_asks_the_guards_again() {
+        let (mut router, _) = Router::new(Counter::new("/").0);
+        let (page, guard) = Guarded::new(Guard::Confirm("Discard?".into()));
+        let _ = router.navigate(Navigation::GoTo(page.into()));
+
+        let _ = router.navigate(Navigation::Back);
+        assert_eq!(router.pending_confirmation(), Some("Discard?"));
+
+        *guard.lock().unwrap() = Guard::Deny;
+        let _ = router.confirm();
+        assert_eq!(router.pending_confirmation(), None);
+        assert_eq!(router.depth(), 2);
+
+        *guard.lock().unwrap() = Guard::Confirm("Really discard?".into());
+        let _ = router.navigate(Navigation::Back);
+        *guard.lock().unwrap() = Guard::Confirm("Discard the draft?".into());
+        let _ = router.confirm();
+        assert_eq!(router.pending_confirmation(), Some("Discard the draft?"));
+        assert_eq!(router.depth(), 2);
+
+        let _ = router.confirm();
+        assert_eq!(router.depth(), 1);
+    }
+
+    #[test]
+    fn nested_confirmations_reach_the_parent() {
+        let mut nested = Router::nested(Counter::new("/nested").0);
+        let _ = nested.navigate(Navigation::GoTo(
+            Guarded::new(Guard::Confirm("Discard?".into())).0.into(),
+        ));
+        let _ = nested.navigate(Navigation::Back);
+
+        let (mut router, _) = Router::new(Counter::new("/").0);
+        let _ = router.navigate(Navigation::GoTo(nested.into()));
+        assert_eq!(router.pending_confirmation(), Some("Discard?"));
+        assert_eq!(router.path(), None);
+
+        let _ = router.confirm();
+        assert_eq!(router.pending_confirmation(), None);
+        assert_eq!(router.path().as_deref(), Some("/nested"));
+    }
+
+    #[test]
+    fn nested_transitions_are_ticked() {
+        let mut nested =
+            Router::nested(Counter::new("/nested").0).animation(Animation::new(Transition::Slide));
+        let _ = nested.navigate(Navigation::GoTo(Counter::new("/nested/next").0.into()));
+
+        let (mut router, _) = Router::new(Counter::new("/").0);
+        let _ = router.navigate(Navigation::GoTo(nested.into()));
+        assert!(router.is_animating());
+
+        router.tick(Instant::now() + std::time::Duration::from_secs(1));
+        assert!(!router.is_animating());
+    }
+
+    #[test]
+    fn restore_rebuilds_a_valid_snapshot() {
+        let (router, _) = Router::restore::<TestRoute>("/\n/a\n/a/b").unwrap();
+        assert_eq!(router.depth(), 3);
+        assert_eq!(router.snapshot().as_deref(), Some("/\n/a\n/a/b"));
+    }
+
+    #[test]
+    fn restore_rejects_broken_snapshots() {
+        for snapshot in ["", "/a/b", "/\n/a/b", "/\n/a\n/a", "/\n/unknown"] {
+            assert!(
+                Router::restore::<TestRoute>(snapshot).is_none(),
+                "{:?} was restored",
+                snapshot
+            );
+        }
+    }
+}
@@ -0,0 +1,187 @@
+//! Tabs, each of them with its own stack of pages.
+
+use crate::router::{AnyPage, Navigation, Routed, Router, StaleMessage};
+use iced::{
+    Element, Length, Subscription, Task,
+    time::Instant,
+    widget::{button, column, row, text},
+};
+
+/// The messages of a [`TabNavigator`].
+#[derive(Debug, Clone)]
+pub enum TabMessage {
+    /// Shows the tab at the given index.
+    ///
+    /// Selecting the tab that is already shown goes back to its root page.
+    Select(usize),
+    /// A message of a page in the tab at the given index.
+    Page(usize, Routed),
+}
+
+/// A tab bar over a list of tabs, each of them owning a [`Router`].
+///
+/// Every message of a page is tagged with the index of its tab, so that it reaches
+/// the stack it belongs to even if another tab is shown by the time it arrives.
+/// Inactive tabs keep their pages, but only the shown tab is subscribed to.
+#[derive(Default)]
+pub struct TabNavigator {
+    tabs: Vec<(String, Router)>,
+    active: usize,
+}
+
+impl TabNavigator {
+    /// Creates a [`TabNavigator`] without tabs.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a tab with the given label and root page.
+    ///
+    /// The returned [`Task`] comes from [`Page::on_enter`](crate::router::Page::on_enter)
+    /// of the root page.
+    pub fn push(&mut self, label: impl Into<String>, root: impl Into<AnyPage>) -> Task<TabMessage> {
+        let (router, task) = Router::new(root);
+        self.push_router(label, router, task)
+    }
+
+    /// Adds a tab with the given label and an existing [`Router`], e.g., an animated one.
+    ///
+    /// `task` is the [`Task`] returned when the [`Router`] was created.
+    /// Unless the tab is shown, [`Page::on_leave`](crate::router::Page::on_leave) of its
+    /// page on top is called right away, since the tab is hidden.
+    pub fn push_router(
+        &mut self,
+        label: impl Into<String>,
+        mut router: Router,
+        task: Task<Routed>,
+    ) -> Task<TabMessage> {
+        let index = self.tabs.len();
+        let task = if index == self.active {
+            task
+        } else {
+            Task::batch([task, router.leave()])
+        };
+        self.tabs.push((label.into(), router));
+        task.map(move |message| TabMessage::Page(index, message))
+    }
+
+    /// Returns the index of the shown tab.
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    /// Handles a [`TabMessage`].
+    ///
+    /// Selecting a tab that does not exist does nothing.
+    /// Returns [`StaleMessage`] if the page of a message is no longer in its tab.
+    pub fn update(&mut self, message: TabMessage) -> Result<Task<TabMessage>, StaleMessage> {
+        match message {
+            TabMessage::Select(index) if index == self.active => match self.tabs.get_mut(index) {
+                Some((_, router)) => {
+                    let task = router.navigate(Navigation::PopToRoot);
+                    Ok(task.map(move |message| TabMessage::Page(index, message)))
+                }
+                None => Ok(Task::none()),
+            },
+            TabMessage::Select(index) if index >= self.tabs.len() => Ok(Task::none()),
+            TabMessage::Select(index) => {
+                let tasks = [self.active, index].map(|index| {
+                    let (_, router) = &mut self.tabs[index];
+                    let task = if index == self.active {
+                        router.leave()
+                    } else {
+                        router.resume()
+                    };
+                    task.map(move |message| TabMessage::Page(index, message))
+                });
+                self.active = index;
+                Ok(Task::batch(tasks))
+            }
+            TabMessage::Page(index, message) => match self.tabs.get_mut(index) {
+                Some((_, router)) => {
+                    let task = router.update(message)?;
+                    Ok(task.map(move |message| TabMessage::Page(index, message)))
+                }
+                None => Err(StaleMessage(message)),
+            },
+        }
+    }
+
+    /// Returns the question of the navigation in the shown tab that waits for a
+    /// confirmation, if any, see [`Router::pending_confirmation`].
+    ///
+    /// The questions of hidden tabs wait until their tab is shown again.
+    pub fn pending_confirmation(&self) -> Option<&str> {
+        let (_, router) = self.tabs.get(self.active)?;
+        router.pending_confirmation()
+    }
+
+    /// Applies the navigation in the shown tab that waits for a confirmation.
+    pub fn confirm(&mut self) -> Task<TabMessage> {
+        let active = self.active;
+        match self.tabs.get_mut(active) {
+            Some((_, router)) => router
+                .confirm()
+                .map(move |message| TabMessage::Page(active, message)),
+            None => Task::none(),
+        }
+    }
+
+    /// Drops the navigation in the shown tab that waits for a confirmation.
+    pub fn cancel(&mut self) {
+        if let Some((_, router)) = self.tabs.get_mut(self.active) {
+            router.cancel();
+        }
+    }
+
+    /// Shows the tab bar above the page on top of the shown tab.
+    pub fn view(&self) -> Element<'_, TabMessage> {
+        let tab_bar = row(self.tabs.iter().enumerate().map(|(index, (label, _))| {
+            button(text(label.as_str()))
+                .style(if index == self.active {
+                    button::primary
+                } else {
+                    button::secondary
+                })
+                .on_press(TabMessage::Select(index))
+                .into()
+        }))
+        .spacing(5);
+
+        let active = self.active;
+        let page = match self.tabs.get(active) {
+            Some((_, router)) => router
+                .view()
+                .map(move |message| TabMessage::Page(active, message)),
+            None => text("No tabs").into(),
+        };
+
+        column![tab_bar, page]
+            .spacing(10)
+            .width(Length::Fill)
+            .into()
+    }
+
+    /// Returns the [`Subscription`] of the page on top of the shown tab.
+    pub fn subscription(&self) -> Subscription<TabMessage> {
+        match self.tabs.get(self.active) {
+            Some((_, router)) => router
+                .subscription()
+                .with(self.active)
+                .map(|(index, message)| TabMessage::Page(index, message)),
+            None => Subscription::none(),
+        }
+    }
+
+    /// Returns `true` if a transition is running in any tab.
+    pub fn is_animating(&self) -> bool {
+        self.tabs.iter().any(|(_, router)| router.is_animating())
+    }
+
+    /// Advances the running transitions of all tabs, see [`Router::tick`].
+    pub fn tick(&mut self, now: Instant) {
+        for (_, router) in &mut self.tabs {
+            router.tick(now);
+        }
+    }
+}
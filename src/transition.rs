@@ -0,0 +1,372 @@
+//! Animated transitions between two pages.
+//!
+//! The [`PageTransition`] widget shows the outgoing and the incoming page at the same
+//! time and moves them according to the progress of the animation.
+//! The progress is advanced by the app, usually on every frame of
+//! [`window::frames`](iced::window::frames).
+
+use iced::{
+    Border, Color, Element, Event, Length, Point, Rectangle, Shadow, Size, Theme, Vector,
+    advanced::{
+        Clipboard, Layout, Shell, Widget,
+        graphics::core::event,
+        layout, mouse, overlay,
+        renderer::{self, Quad},
+        widget::{Operation, Tree},
+    },
+    time::Duration,
+};
+
+/// How the incoming page replaces the outgoing page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transition {
+    /// Both pages slide side by side.
+    Slide,
+    /// The outgoing page fades out to the background, then the incoming page fades in.
+    Fade,
+    /// The new page slides over the old page, which moves a little behind it.
+    /// Going back slides the old page away again.
+    Push,
+}
+
+/// How the progress of an animation speeds up and slows down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+    /// Starts slowly.
+    EaseIn,
+    /// Ends slowly.
+    EaseOut,
+    /// Starts and ends slowly.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Maps the elapsed fraction of the animation, between `0.0` and `1.0`, to its progress.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+/// Whether the navigation goes deeper into the stack or back out of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// A page is pushed or replaced.
+    Forward,
+    /// Pages are popped.
+    Backward,
+}
+
+/// The configuration of a page transition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Animation {
+    /// The kind of the transition.
+    pub transition: Transition,
+    /// How long the transition takes.
+    pub duration: Duration,
+    /// How the progress of the transition changes over time.
+    pub easing: Easing,
+}
+
+impl Animation {
+    /// Creates an [`Animation`] of 300 milliseconds with [`Easing::EaseInOut`].
+    pub fn new(transition: Transition) -> Self {
+        Self {
+            transition,
+            duration: Duration::from_millis(300),
+            easing: Easing::EaseInOut,
+        }
+    }
+
+    /// Sets the duration of the [`Animation`].
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the easing of the [`Animation`].
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Returns the eased progress, between `0.0` and `1.0`, after `elapsed` time.
+    pub fn progress(&self, elapsed: Duration) -> f32 {
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+        self.easing
+            .apply(elapsed.as_secs_f32() / self.duration.as_secs_f32())
+    }
+}
+
+/// Shows an incoming page, and an outgoing page while a transition runs.
+///
+/// Only the incoming page receives events and shows its overlays, such as the menu of a
+/// [`pick_list`](iced::widget::pick_list).
+pub struct PageTransition<'a, Message, Renderer> {
+    // The incoming page comes first, so that its widget state is kept when the
+    // outgoing page is gone.
+    pages: Vec<Element<'a, Message, Theme, Renderer>>,
+    transition: Transition,
+    direction: Direction,
+    progress: f32,
+}
+
+impl<'a, Message, Renderer> PageTransition<'a, Message, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates a [`PageTransition`] that shows `incoming` only.
+    pub fn new(incoming: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            pages: vec![incoming.into()],
+            transition: Transition::Slide,
+            direction: Direction::Forward,
+            progress: 1.0,
+        }
+    }
+
+    /// Shows `outgoing` as well, with the given state of the transition.
+    pub fn outgoing(
+        mut self,
+        outgoing: impl Into<Element<'a, Message, Theme, Renderer>>,
+        transition: Transition,
+        direction: Direction,
+        progress: f32,
+    ) -> Self {
+        self.pages.truncate(1);
+        self.pages.push(outgoing.into());
+        self.transition = transition;
+        self.direction = direction;
+        self.progress = progress.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Returns the horizontal offsets of the incoming and the outgoing page.
+    fn offsets(&self, width: f32) -> (f32, f32) {
+        let p = self.progress;
+        let sign = match self.direction {
+            Direction::Forward => 1.0,
+            Direction::Backward => -1.0,
+        };
+
+        match (self.transition, self.direction) {
+            (Transition::Slide, _) => (sign * (1.0 - p) * width, -sign * p * width),
+            (Transition::Fade, _) => (0.0, 0.0),
+            (Transition::Push, Direction::Forward) => ((1.0 - p) * width, -0.3 * p * width),
+            (Transition::Push, Direction::Backward) => (-0.3 * (1.0 - p) * width, p * width),
+        }
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Theme, Renderer> for PageTransition<'_, Message, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fill,
+            height: Length::Fill,
+        }
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.pages);
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.pages.iter().map(Tree::new).collect()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = limits.max();
+        let (incoming, outgoing) = self.offsets(size.width);
+
+        let children = self
+            .pages
+            .iter()
+            .zip(&mut tree.children)
+            .zip([incoming, outgoing])
+            .map(|((page, tree), x)| {
+                page.as_widget()
+                    .layout(tree, renderer, &limits.loose())
+                    .move_to(Point::new(x, 0.0))
+            })
+            .collect();
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let mut pages: Vec<_> = self
+            .pages
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .collect();
+
+        // Decide which page is visible and which one is drawn on top.
+        let mut fade = 0.0;
+        if pages.len() > 1 {
+            match (self.transition, self.direction) {
+                (Transition::Fade, _) if self.progress < 0.5 => {
+                    pages.remove(0);
+                    fade = self.progress * 2.0;
+                }
+                (Transition::Fade, _) => {
+                    pages.truncate(1);
+                    fade = (1.0 - self.progress) * 2.0;
+                }
+                (Transition::Push, Direction::Backward) => {}
+                _ => pages.reverse(),
+            }
+        }
+
+        let background = theme.palette().background;
+        let fill = |renderer: &mut Renderer, bounds: Rectangle, color: Color| {
+            renderer.fill_quad(
+                Quad {
+                    bounds,
+                    border: Border::default(),
+                    shadow: Shadow::default(),
+                },
+                color,
+            );
+        };
+
+        renderer.with_layer(bounds, |renderer| {
+            let animating = pages.len() > 1;
+            for ((page, tree), layout) in pages {
+                // Pages are usually transparent, so give them a background while they
+                // overlap each other.
+                if animating {
+                    let x = layout.bounds().x;
+                    fill(renderer, Rectangle { x, ..bounds }, background);
+                }
+                page.as_widget()
+                    .draw(tree, renderer, theme, style, layout, cursor, viewport);
+            }
+
+            if fade > 0.0 {
+                fill(
+                    renderer,
+                    bounds,
+                    Color {
+                        a: fade,
+                        ..background
+                    },
+                );
+            }
+        });
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.pages[0].as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.pages[0].as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.pages[0].as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.pages[0].as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<PageTransition<'a, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(widget: PageTransition<'a, Message, Renderer>) -> Self {
+        Self::new(widget)
+    }
+}